@@ -0,0 +1,193 @@
+use std::iter::FusedIterator;
+
+use crate::ipv4::IPv4;
+
+/// Iterates the addresses between two bounds; `nth` and `nth_back` jump
+/// without walking the skipped addresses.
+#[derive(Clone)]
+pub struct Addresses {
+	front: u64,
+	back: u64,
+}
+
+impl Addresses {
+	pub(crate) fn new(first: &IPv4, last: &IPv4) -> Addresses {
+		Addresses {
+			front: first.to_u32() as u64,
+			back: last.to_u32() as u64 + 1,
+		}
+	}
+
+	/// The number of addresses left, which is up to 2^32 and so may not fit
+	/// in a `usize`.
+	pub fn remaining(&self) -> u64 {
+		self.back - self.front
+	}
+}
+
+impl Iterator for Addresses {
+	type Item = IPv4;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			let address = IPv4::from(self.front as u32);
+			self.front += 1;
+			Some(address)
+		} else {
+			None
+		}
+	}
+
+	/// Exact whenever the count fits in a `usize`, which on 64-bit targets is
+	/// always; a 32-bit target gets `(usize::MAX, None)` for a whole /0.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match usize::try_from(self.remaining()) {
+			Ok(remaining) => (remaining, Some(remaining)),
+			Err(_) => (usize::MAX, None),
+		}
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.front = self.front.saturating_add(n as u64).min(self.back);
+		self.next()
+	}
+
+	/// Panics if the count does not fit in a `usize`, as the default `count`
+	/// would overflow.
+	fn count(self) -> usize {
+		usize::try_from(self.remaining()).expect("address count overflows usize")
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl DoubleEndedIterator for Addresses {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			self.back -= 1;
+			Some(IPv4::from(self.back as u32))
+		} else {
+			None
+		}
+	}
+
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.back = self.back.saturating_sub(n as u64).max(self.front);
+		self.next_back()
+	}
+}
+
+/// Only where every count up to 2^32 fits in a `usize`.
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for Addresses {}
+
+impl FusedIterator for Addresses {}
+
+#[cfg(test)]
+mod tests {
+	use crate::addresses::Addresses;
+	use crate::ipv4::IPv4;
+
+	fn fixture(first: &str, last: &str) -> Addresses {
+		Addresses::new(
+			&IPv4::try_from(first).unwrap(),
+			&IPv4::try_from(last).unwrap(),
+		)
+	}
+
+	fn collect<T: Iterator<Item = IPv4>>(scr: T) -> Vec<String> {
+		scr.map(|a| format!("{}", a)).collect()
+	}
+
+	#[test]
+	fn next_test() {
+		assert_eq!(
+			collect(fixture("10.0.0.254", "10.0.1.1")),
+			["10.0.0.254", "10.0.0.255", "10.0.1.0", "10.0.1.1"]
+		);
+		assert_eq!(collect(fixture("10.0.0.1", "10.0.0.1")), ["10.0.0.1"]);
+	}
+
+	#[test]
+	fn next_back_test() {
+		assert_eq!(
+			collect(fixture("10.0.0.1", "10.0.0.3").rev()),
+			["10.0.0.3", "10.0.0.2", "10.0.0.1"]
+		);
+
+		let mut actual = fixture("10.0.0.1", "10.0.0.3");
+		assert_eq!(actual.next(), Some(IPv4::try_from("10.0.0.1").unwrap()));
+		assert_eq!(
+			actual.next_back(),
+			Some(IPv4::try_from("10.0.0.3").unwrap())
+		);
+		assert_eq!(actual.next(), Some(IPv4::try_from("10.0.0.2").unwrap()));
+		assert_eq!(actual.next_back(), None);
+		assert_eq!(actual.next(), None);
+	}
+
+	#[test]
+	fn remaining_test() {
+		let mut actual = fixture("0.0.0.0", "255.255.255.255");
+		assert_eq!(actual.remaining(), 1 << 32);
+
+		let expected = match usize::try_from(1u64 << 32) {
+			Ok(count) => (count, Some(count)),
+			Err(_) => (usize::MAX, None),
+		};
+		assert_eq!(actual.size_hint(), expected);
+
+		actual.next();
+		actual.next_back();
+		assert_eq!(actual.remaining(), (1 << 32) - 2);
+		assert_eq!(fixture("10.0.0.1", "10.0.0.3").size_hint(), (3, Some(3)));
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "64")]
+	fn len_test() {
+		let mut actual = fixture("0.0.0.0", "255.255.255.255");
+		assert_eq!(actual.len(), 1 << 32);
+
+		actual.next();
+		actual.next_back();
+		assert_eq!(actual.len(), (1 << 32) - 2);
+		assert_eq!(actual.count(), (1 << 32) - 2);
+	}
+
+	#[test]
+	fn nth_test() {
+		let mut actual = fixture("0.0.0.0", "255.255.255.255");
+
+		assert_eq!(actual.nth(0x0a00_0000), Some(IPv4::from(0x0a00_0000)));
+		assert_eq!(actual.nth_back(0xff), Some(IPv4::from(0xffff_ff00)));
+		assert_eq!(actual.next_back(), Some(IPv4::from(0xffff_feff)));
+		assert_eq!(actual.nth(usize::MAX), None);
+		assert_eq!(actual.next(), None);
+
+		assert_eq!(
+			fixture("0.0.0.0", "255.255.255.255").last(),
+			Some(IPv4::from(0xffff_ffff))
+		);
+	}
+
+	#[test]
+	fn step_by_test() {
+		assert_eq!(
+			collect(fixture("0.0.0.0", "255.255.255.255").step_by(1 << 30)),
+			["0.0.0.0", "64.0.0.0", "128.0.0.0", "192.0.0.0"]
+		);
+	}
+
+	/// `StepBy` is only double-ended over an `ExactSizeIterator`.
+	#[test]
+	#[cfg(target_pointer_width = "64")]
+	fn step_by_rev_test() {
+		assert_eq!(
+			collect(fixture("10.0.0.0", "10.0.0.9").step_by(4).rev()),
+			["10.0.0.8", "10.0.0.4", "10.0.0.0"]
+		);
+	}
+}
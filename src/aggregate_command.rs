@@ -0,0 +1,113 @@
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader};
+
+use cidr_checker::{cidr_list, IPv4ParseMode, IpCidr};
+
+use crate::command_error::CommandError;
+
+pub fn run<'a, T: Iterator<Item = &'a String>>(scr: &mut T) -> Result<Vec<IpCidr>, CommandError> {
+	let mut cidrs = Vec::new();
+	let mut has_input = false;
+
+	for path in scr {
+		has_input = true;
+
+		if path == "-" {
+			cidrs.extend(read_list("-", io::stdin().lock(), IPv4ParseMode::Lenient)?);
+		} else if path.starts_with('-') {
+			return Err(CommandError::UnknownOption(path.clone()));
+		} else {
+			let file = File::open(path).map_err(|e| CommandError::Io(path.clone(), e))?;
+			let reader = BufReader::new(file);
+			cidrs.extend(read_list(path, reader, IPv4ParseMode::Lenient)?);
+		}
+	}
+
+	if !has_input {
+		cidrs.extend(read_list("-", io::stdin().lock(), IPv4ParseMode::Lenient)?);
+	}
+
+	Ok(cidr_list::aggregate(cidrs))
+}
+
+/// Reads one CIDR of either family per line. Blank lines and `#` comments
+/// are skipped. IPv4 blocks are read as `mode` allows.
+pub fn read_list<R: BufRead>(
+	source: &str,
+	reader: R,
+	mode: IPv4ParseMode,
+) -> Result<Vec<IpCidr>, CommandError> {
+	let mut result = Vec::new();
+
+	for (index, line) in reader.lines().enumerate() {
+		let line = line.map_err(|e| CommandError::Io(source.to_string(), e))?;
+		let text = line.split('#').next().unwrap().trim();
+
+		if text.is_empty() {
+			continue;
+		}
+
+		let cidr = IpCidr::parse_with(text, mode)
+			.map_err(|e| CommandError::InvalidLine(source.to_string(), index + 1, e))?;
+		result.push(cidr);
+	}
+
+	Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::Cursor;
+
+	use cidr_checker::IPv4ParseMode;
+
+	use crate::aggregate_command::{read_list, run};
+	use crate::command_error::CommandError;
+
+	#[test]
+	fn read_list_test() {
+		let input =
+			"10.0.0.0/8\n\n# comment\n192.168.10.0/24 # office\n  172.16.0.0/12  \n2001:db8::/32\n";
+		let actual: Vec<_> = read_list("-", Cursor::new(input), IPv4ParseMode::Lenient)
+			.unwrap()
+			.iter()
+			.map(|c| format!("{}", c))
+			.collect();
+
+		assert_eq!(
+			actual,
+			[
+				"10.0.0.0/8",
+				"192.168.10.0/24",
+				"172.16.0.0/12",
+				"2001:db8::/32"
+			]
+		);
+	}
+
+	#[test]
+	fn read_list_error_test() {
+		let input = "10.0.0.0/8\n10.0.0.1/8\n";
+		let actual = read_list("rules.txt", Cursor::new(input), IPv4ParseMode::Lenient);
+
+		if let Err(CommandError::InvalidLine(source, line, _)) = actual {
+			assert_eq!(source, "rules.txt");
+			assert_eq!(line, 2);
+		} else {
+			unreachable!()
+		}
+	}
+
+	#[test]
+	fn run_error_test() {
+		let args = ["-x".to_string()];
+		assert!(matches!(
+			run(&mut args.iter()),
+			Err(CommandError::UnknownOption(_))
+		));
+
+		let args = ["/nonexistent/cidr_checker/list.txt".to_string()];
+		assert!(matches!(run(&mut args.iter()), Err(CommandError::Io(..))));
+	}
+}
@@ -0,0 +1,415 @@
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+use cidr_checker::{IPv4ParseMode, IpAddress, IpCidrSet};
+
+use crate::aggregate_command::read_list;
+use crate::command_error::CommandError;
+
+pub struct BatchOptions {
+	rules: Option<String>,
+	column: Option<usize>,
+	invert: bool,
+	count: bool,
+	only_matching: bool,
+	mode: IPv4ParseMode,
+	inputs: Vec<String>,
+}
+
+impl BatchOptions {
+	pub fn parse<'a, T: Iterator<Item = &'a String>>(scr: &mut T) -> Result<Self, CommandError> {
+		let mut data = BatchOptions {
+			rules: None,
+			column: None,
+			invert: false,
+			count: false,
+			only_matching: false,
+			mode: IPv4ParseMode::Lenient,
+			inputs: Vec::new(),
+		};
+
+		while let Some(option) = scr.next() {
+			match option.as_str() {
+				"-v" => data.invert = true,
+				"-c" => data.count = true,
+				"-o" => data.only_matching = true,
+				"--strict" => data.mode = IPv4ParseMode::Strict,
+				"-f" | "-k" => {
+					let value = scr
+						.next()
+						.ok_or_else(|| CommandError::MissingValue(option.clone()))?;
+
+					if option == "-f" {
+						if data.rules.is_some() {
+							return Err(CommandError::DuplicateOption(option.clone()));
+						}
+						data.rules = Some(value.clone());
+					} else {
+						if data.column.is_some() {
+							return Err(CommandError::DuplicateOption(option.clone()));
+						}
+						let column = value
+							.parse::<usize>()
+							.ok()
+							.filter(|c| *c > 0)
+							.ok_or_else(|| CommandError::InvalidColumn(value.clone()))?;
+						data.column = Some(column);
+					}
+				}
+				"-" => data.inputs.push(option.clone()),
+				_ if option.starts_with('-') => {
+					return Err(CommandError::UnknownOption(option.clone()))
+				}
+				_ => data.inputs.push(option.clone()),
+			}
+		}
+
+		if data.rules.is_none() {
+			return Err(CommandError::MissingRules);
+		}
+
+		Ok(data)
+	}
+
+	/// The address field of `line`: the whole line, or with `-k` its column
+	/// counted from 1. Columns are split on commas; quoted fields that contain
+	/// commas are not supported.
+	fn field<'l>(&self, line: &'l str) -> Option<&'l str> {
+		let field = match self.column {
+			None => line,
+			Some(column) => line.split(',').nth(column - 1)?,
+		};
+
+		Some(field.trim().trim_matches('"'))
+	}
+}
+
+/// Runs `batch` and returns whether any line was selected, like `grep`.
+pub fn run<'a, T: Iterator<Item = &'a String>>(scr: &mut T) -> Result<bool, CommandError> {
+	let options = BatchOptions::parse(scr)?;
+	let rules = read_rules(options.rules.as_ref().unwrap(), options.mode)?;
+
+	let stdout = io::stdout();
+	let mut out = BufWriter::new(stdout.lock());
+	let mut report = |e: CommandError| eprintln!("{}", e);
+	let mut any_selected = false;
+
+	let stdin = ["-".to_string()];
+	let inputs = if options.inputs.is_empty() {
+		&stdin[..]
+	} else {
+		&options.inputs[..]
+	};
+
+	for path in inputs {
+		let selected = if path == "-" {
+			let reader = io::stdin().lock();
+			filter(path, reader, &rules, &options, &mut out, &mut report)?
+		} else {
+			let file = File::open(path).map_err(|e| CommandError::Io(path.clone(), e))?;
+			let reader = BufReader::new(file);
+			filter(path, reader, &rules, &options, &mut out, &mut report)?
+		};
+
+		if options.count {
+			write_count(&mut out, path, selected, inputs.len() > 1)?;
+		}
+
+		any_selected |= selected > 0;
+	}
+
+	out.flush().map_err(write_error)?;
+	Ok(any_selected)
+}
+
+fn read_rules(path: &str, mode: IPv4ParseMode) -> Result<IpCidrSet, CommandError> {
+	let file = File::open(path).map_err(|e| CommandError::Io(path.to_string(), e))?;
+	let cidrs = read_list(path, BufReader::new(file), mode)?;
+	Ok(cidrs.into_iter().collect())
+}
+
+/// Prints the `-c` count of one input, prefixed with its name when there are
+/// several, as `grep -c` does.
+fn write_count<W: Write>(
+	out: &mut W,
+	source: &str,
+	selected: usize,
+	named: bool,
+) -> Result<(), CommandError> {
+	if named {
+		writeln!(out, "{}:{}", source, selected).map_err(write_error)
+	} else {
+		writeln!(out, "{}", selected).map_err(write_error)
+	}
+}
+
+fn write_error(e: io::Error) -> CommandError {
+	CommandError::Io("stdout".to_string(), e)
+}
+
+/// Prints the lines of `reader` whose address is (or with `-v`, is not) in
+/// `rules`, and returns how many were selected. Blank lines are skipped;
+/// lines without a valid address are passed to `report` and never selected.
+/// Lines need not be UTF-8: invalid bytes never form an address, and
+/// selected lines are printed byte for byte.
+fn filter<R: BufRead, W: Write, F: FnMut(CommandError)>(
+	source: &str,
+	mut reader: R,
+	rules: &IpCidrSet,
+	options: &BatchOptions,
+	out: &mut W,
+	report: &mut F,
+) -> Result<usize, CommandError> {
+	let mut selected = 0;
+	let mut buf = Vec::new();
+
+	for index in 0.. {
+		buf.clear();
+		let read = reader
+			.read_until(b'\n', &mut buf)
+			.map_err(|e| CommandError::Io(source.to_string(), e))?;
+
+		if read == 0 {
+			break;
+		}
+
+		let bytes = buf.strip_suffix(b"\n").unwrap_or(&buf);
+		let bytes = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+		let line = String::from_utf8_lossy(bytes);
+
+		if line.trim().is_empty() {
+			continue;
+		}
+
+		let field = match options.field(&line) {
+			Some(field) => field,
+			None => {
+				let column = options.column.unwrap();
+				report(CommandError::MissingColumn(
+					source.to_string(),
+					index + 1,
+					column,
+				));
+				continue;
+			}
+		};
+
+		let address = match IpAddress::parse_with(field, options.mode) {
+			Ok(address) => address,
+			Err(e) => {
+				report(CommandError::InvalidAddressLine(
+					source.to_string(),
+					index + 1,
+					e,
+				));
+				continue;
+			}
+		};
+
+		if rules.contains(&address) == options.invert {
+			continue;
+		}
+
+		selected += 1;
+
+		if options.count {
+			continue;
+		}
+
+		if options.only_matching {
+			writeln!(out, "{}", address).map_err(write_error)?;
+		} else {
+			out.write_all(bytes).map_err(write_error)?;
+			out.write_all(b"\n").map_err(write_error)?;
+		}
+	}
+
+	Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::Cursor;
+
+	use cidr_checker::{IPv4ParseMode, IpCidr, IpCidrSet};
+
+	use crate::batch_command::{filter, write_count, BatchOptions};
+	use crate::command_error::CommandError;
+
+	fn options(args: &[&str]) -> Result<BatchOptions, CommandError> {
+		let args: Vec<_> = args.iter().map(|s| s.to_string()).collect();
+		BatchOptions::parse(&mut args.iter())
+	}
+
+	fn rules() -> IpCidrSet {
+		["10.0.0.0/8", "192.168.10.0/24", "2001:db8::/32"]
+			.iter()
+			.map(|c| IpCidr::try_from(*c).unwrap())
+			.collect()
+	}
+
+	fn run_filter(args: &[&str], input: &str) -> (usize, String, Vec<String>) {
+		let (selected, out, errors) = run_filter_bytes(args, input.as_bytes());
+		(selected, String::from_utf8(out).unwrap(), errors)
+	}
+
+	fn run_filter_bytes(args: &[&str], input: &[u8]) -> (usize, Vec<u8>, Vec<String>) {
+		let options = options(args).unwrap();
+		let mut out = Vec::new();
+		let mut errors = Vec::new();
+
+		let selected = filter(
+			"-",
+			Cursor::new(input),
+			&rules(),
+			&options,
+			&mut out,
+			&mut |e| errors.push(e.to_string()),
+		)
+		.unwrap();
+
+		(selected, out, errors)
+	}
+
+	const INPUT: &str = "10.1.2.3\n172.16.0.1\n\n192.168.10.7\n2001:db8::1\n::1\n";
+
+	#[test]
+	fn filter_test() {
+		let (selected, out, errors) = run_filter(&["-f", "rules.txt"], INPUT);
+		assert_eq!(selected, 3);
+		assert_eq!(out, "10.1.2.3\n192.168.10.7\n2001:db8::1\n");
+		assert!(errors.is_empty());
+	}
+
+	#[test]
+	fn filter_invert_test() {
+		let (selected, out, _) = run_filter(&["-f", "rules.txt", "-v"], INPUT);
+		assert_eq!(selected, 2);
+		assert_eq!(out, "172.16.0.1\n::1\n");
+	}
+
+	#[test]
+	fn filter_count_test() {
+		let (selected, out, _) = run_filter(&["-c", "-f", "rules.txt"], INPUT);
+		assert_eq!(selected, 3);
+		assert_eq!(out, "");
+	}
+
+	#[test]
+	fn write_count_test() {
+		let mut out = Vec::new();
+		write_count(&mut out, "-", 3, false).unwrap();
+		write_count(&mut out, "a.log", 0, true).unwrap();
+		assert_eq!(String::from_utf8(out).unwrap(), "3\na.log:0\n");
+	}
+
+	#[test]
+	fn filter_column_test() {
+		let input = "time,ip,path\n\
+			12:00,10.1.2.3,/\n\
+			12:01, \"172.16.0.1\" ,/login\n\
+			12:02,192.168.10.7,/admin\n\
+			12:03\n";
+
+		let (selected, out, errors) = run_filter(&["-f", "rules.txt", "-k", "2"], input);
+		assert_eq!(selected, 2);
+		assert_eq!(out, "12:00,10.1.2.3,/\n12:02,192.168.10.7,/admin\n");
+		assert_eq!(
+			errors,
+			[
+				"-:1: octet 1 of \"ip\" (\"ip\") is not a number from 0 to 255",
+				"-:5: no column 2"
+			]
+		);
+
+		let (_, out, _) = run_filter(&["-f", "rules.txt", "-k", "2", "-o", "-v"], input);
+		assert_eq!(out, "172.16.0.1\n");
+	}
+
+	#[test]
+	fn filter_invalid_line_test() {
+		let input = "10.1.2.3\n10.0.0\n192.168.10.7\n";
+		let (selected, out, errors) = run_filter(&["-f", "rules.txt"], input);
+
+		assert_eq!(selected, 2);
+		assert_eq!(out, "10.1.2.3\n192.168.10.7\n");
+		assert_eq!(
+			errors,
+			["-:2: \"10.0.0\" is not an IPv4 address: expected four dot-separated octets"]
+		);
+	}
+
+	#[test]
+	fn filter_non_utf8_test() {
+		let input = b"10.1.2.3,caf\xe9\n\xff\xfe\n192.168.10.7\r\n172.16.0.1,\xe9\n";
+
+		let (selected, out, errors) = run_filter_bytes(&["-f", "rules.txt", "-k", "1"], input);
+		assert_eq!(selected, 2);
+		assert_eq!(out, b"10.1.2.3,caf\xe9\n192.168.10.7\n");
+		assert_eq!(
+			errors,
+			["-:2: octet 1 of \"\u{fffd}\u{fffd}\" (\"\u{fffd}\u{fffd}\") is not a number from 0 to 255"]
+		);
+
+		let (selected, out, _) = run_filter_bytes(&["-f", "rules.txt", "-k", "1", "-v"], input);
+		assert_eq!(selected, 1);
+		assert_eq!(out, b"172.16.0.1,\xe9\n");
+	}
+
+	#[test]
+	fn filter_strict_test() {
+		let input = "10.1.2.3\n010.1.2.3\n192.168.10.07\n";
+
+		let (selected, _, errors) = run_filter(&["-f", "rules.txt"], input);
+		assert_eq!(selected, 3);
+		assert!(errors.is_empty());
+
+		let (selected, out, errors) = run_filter(&["--strict", "-f", "rules.txt"], input);
+		assert_eq!(selected, 1);
+		assert_eq!(out, "10.1.2.3\n");
+		assert_eq!(
+			errors,
+			[
+				"-:2: octet 1 of \"010.1.2.3\" (\"010\") has a leading zero, which may be read as octal",
+				"-:3: octet 4 of \"192.168.10.07\" (\"07\") has a leading zero, which may be read as octal"
+			]
+		);
+	}
+
+	#[test]
+	fn parse_test() {
+		let fixture = options(&["-f", "rules.txt", "-v", "-o", "a.log", "-", "b.log"]).unwrap();
+		assert_eq!(fixture.rules.as_deref(), Some("rules.txt"));
+		assert!(fixture.invert && fixture.only_matching && !fixture.count);
+		assert_eq!(fixture.mode, IPv4ParseMode::Lenient);
+		assert_eq!(fixture.inputs, ["a.log", "-", "b.log"]);
+
+		let fixture = options(&["--strict", "-f", "rules.txt"]).unwrap();
+		assert_eq!(fixture.mode, IPv4ParseMode::Strict);
+	}
+
+	#[test]
+	fn parse_error_test() {
+		assert!(matches!(
+			options(&["a.log"]),
+			Err(CommandError::MissingRules)
+		));
+		assert!(matches!(
+			options(&["-f"]),
+			Err(CommandError::MissingValue(_))
+		));
+		assert!(matches!(
+			options(&["-f", "a", "-f", "b"]),
+			Err(CommandError::DuplicateOption(_))
+		));
+		assert!(matches!(
+			options(&["-f", "a", "-k", "0"]),
+			Err(CommandError::InvalidColumn(_))
+		));
+		assert!(matches!(
+			options(&["-f", "a", "-x"]),
+			Err(CommandError::UnknownOption(_))
+		));
+	}
+}
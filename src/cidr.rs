@@ -1,30 +1,117 @@
 use std::fmt::{Debug, Display, Formatter};
+use std::net::SocketAddrV4;
+use std::str::FromStr;
 
+use crate::addresses::Addresses;
 use crate::cidr_error::CidrError;
+use crate::cidr_list;
 use crate::ipv4::IPv4;
 use crate::ipv4_mask::IPv4Mask;
+use crate::ipv4_mask_error::IPv4MaskError;
 use crate::ipv4_network_range::IPv4NetworkRange;
-
-#[derive(Eq, PartialEq)]
+use crate::ipv4_parse_mode::IPv4ParseMode;
+use crate::prefix::{FamilyPrefix, Prefix};
+use crate::special_purpose;
+use crate::special_purpose::SpecialPurposeEntry;
+use crate::subnets::Subnets;
+
+/// An IPv4 CIDR block. The address must be the network address: parsing
+/// `10.0.0.1/8` fails, while `from_host` masks the host bits off.
+///
+/// ```
+/// use cidr_checker::{Cidr, IPv4};
+///
+/// let cidr = Cidr::try_from("192.168.10.0/24")?;
+/// assert!(cidr.contains(&IPv4::try_from("192.168.10.15")?));
+/// assert_eq!(cidr.broadcast_address().to_string(), "192.168.10.255");
+/// assert_eq!(cidr.usable_hosts(), 254);
+/// assert!(Cidr::try_from("192.168.10.1/24").is_err());
+/// # Ok::<(), cidr_checker::Error>(())
+/// ```
+///
+/// Ordered by network address, then by prefix length.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
 pub struct Cidr(IPv4, IPv4Mask);
 
 impl Cidr {
 	pub fn new(address: IPv4, subnet: IPv4Mask) -> Result<Cidr, CidrError> {
 		let tmp = subnet.network_address(&address);
 		if tmp != address {
-			Err(CidrError::InvalidAddressOrMask)
+			Err(CidrError::InvalidAddressOrMask {
+				address,
+				mask: subnet,
+			})
 		} else {
 			Ok(Cidr(address, subnet))
 		}
 	}
 
+	/// Parses `value` like `try_from(&str)`, reading the address and the
+	/// prefix as `mode` allows (see `IPv4Mask::parse_with`).
+	pub fn parse_with(value: &str, mode: IPv4ParseMode) -> Result<Cidr, CidrError> {
+		let (address, mask) = parse_parts(value, mode)?;
+		Cidr::new(address, mask)
+	}
+
+	pub fn from_host(address: IPv4, subnet: IPv4Mask) -> Cidr {
+		Cidr(subnet.network_address(&address), subnet)
+	}
+
 	pub fn contains(&self, address: &IPv4) -> bool {
 		let tmp = self.1.network_address(address);
 		tmp == self.0
 	}
 
+	pub fn contains_socket(&self, socket: &SocketAddrV4) -> bool {
+		self.contains(&IPv4::from(*socket.ip()))
+	}
+
+	pub fn contains_cidr(&self, other: &Cidr) -> bool {
+		self.1.cidr() <= other.1.cidr() && self.contains(&other.0)
+	}
+
+	pub fn overlaps(&self, other: &Cidr) -> bool {
+		self.contains_cidr(other) || other.contains_cidr(self)
+	}
+
+	pub fn is_subnet_of(&self, other: &Cidr) -> bool {
+		other.contains_cidr(self)
+	}
+
+	pub fn is_supernet_of(&self, other: &Cidr) -> bool {
+		self.contains_cidr(other)
+	}
+
+	/// Two CIDR blocks are either nested or disjoint, so the intersection is
+	/// the smaller block or nothing.
+	pub fn intersection(&self, other: &Cidr) -> Option<Cidr> {
+		if self.contains_cidr(other) {
+			Some(*other)
+		} else if other.contains_cidr(self) {
+			Some(*self)
+		} else {
+			None
+		}
+	}
+
+	/// The minimal, sorted list of blocks covering this block minus `other`.
+	pub fn exclude(&self, other: &Cidr) -> Vec<Cidr> {
+		let mut result = cidr_list::exclude(*self, other);
+		result.sort();
+		result
+	}
+
+	/// Every special-purpose registry entry overlapping this block; a block
+	/// can be only partially special.
+	pub fn special_purpose(&self) -> Vec<&'static SpecialPurposeEntry> {
+		special_purpose::registry()
+			.iter()
+			.filter(|entry| entry.cidr().overlaps(self))
+			.collect()
+	}
+
 	pub fn network_range(&self) -> IPv4NetworkRange {
-		IPv4NetworkRange::new(self.0, self.broadcast_address())
+		IPv4NetworkRange::new(self.0, self.broadcast_address()).unwrap()
 	}
 
 	pub fn address(&self) -> &IPv4 {
@@ -39,11 +126,215 @@ impl Cidr {
 		self.1.broadcast_address(&self.0)
 	}
 
+	pub fn size(&self) -> u64 {
+		self.network_range().size()
+	}
+
+	/// Follows RFC 3021 for /31 (both addresses usable) and treats /32 as a
+	/// single usable host.
+	pub fn usable_hosts(&self) -> u64 {
+		self.network_range().usable_hosts()
+	}
+
+	pub fn first_host(&self) -> IPv4 {
+		self.network_range().first_host()
+	}
+
+	pub fn last_host(&self) -> IPv4 {
+		self.network_range().last_host()
+	}
+
+	pub fn addresses(&self) -> Addresses {
+		self.network_range().addresses()
+	}
+
+	/// Only the usable hosts, with the same rules as `usable_hosts`.
+	pub fn hosts(&self) -> Addresses {
+		self.network_range().hosts()
+	}
+
+	pub fn subnets(&self, prefix: u8) -> Result<Subnets, CidrError> {
+		let mask = self.child_mask(prefix)?;
+		Ok(Subnets::new(&self.0, mask, self.subnet_count(&mask)))
+	}
+
+	pub fn nth_subnet(&self, prefix: u8, n: u64) -> Result<Cidr, CidrError> {
+		self.subnets(prefix)?
+			.get(n)
+			.ok_or(CidrError::SubnetIndexOutOfRange {
+				cidr: *self,
+				prefix,
+				index: n,
+			})
+	}
+
+	pub fn split(&self) -> Result<(Cidr, Cidr), CidrError> {
+		let prefix = self.1.cidr() + 1;
+		Ok((self.nth_subnet(prefix, 0)?, self.nth_subnet(prefix, 1)?))
+	}
+
+	pub fn supernet(&self) -> Option<Cidr> {
+		match self.1.cidr() {
+			0 => None,
+			prefix => self.supernet_at(prefix - 1).ok(),
+		}
+	}
+
+	pub fn supernet_at(&self, prefix: u8) -> Result<Cidr, CidrError> {
+		let mask = IPv4Mask::new(prefix).map_err(CidrError::PrefixError)?;
+
+		if prefix > self.1.cidr() {
+			Err(CidrError::PrefixTooLong {
+				cidr: *self,
+				prefix,
+			})
+		} else {
+			Ok(Cidr::from_host(self.0, mask))
+		}
+	}
+
+	/// The other half of this block's supernet, or `None` for `/0`.
+	pub fn sibling(&self) -> Option<Cidr> {
+		match self.1.cidr() {
+			0 => None,
+			prefix => {
+				let bit = 1u32 << (32 - prefix);
+				Some(Cidr(IPv4::from(self.0.to_u32() ^ bit), self.1))
+			}
+		}
+	}
+
+	/// True when `other` is this block's sibling, i.e. the two can be
+	/// summarized into their common supernet.
+	pub fn is_adjacent(&self, other: &Cidr) -> bool {
+		self.sibling().is_some_and(|sibling| &sibling == other)
+	}
+
+	fn child_mask(&self, prefix: u8) -> Result<IPv4Mask, CidrError> {
+		let mask = IPv4Mask::new(prefix).map_err(CidrError::PrefixError)?;
+
+		if prefix < self.1.cidr() {
+			Err(CidrError::PrefixTooShort {
+				cidr: *self,
+				prefix,
+			})
+		} else {
+			Ok(mask)
+		}
+	}
+
+	fn subnet_count(&self, mask: &IPv4Mask) -> u64 {
+		1u64 << (mask.cidr() - self.1.cidr())
+	}
+
 	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		write!(f, "{}{}", self.0, self.1)
 	}
 }
 
+pub(crate) fn parse_parts(value: &str, mode: IPv4ParseMode) -> Result<(IPv4, IPv4Mask), CidrError> {
+	let (address, prefix) = match value.split_once('/') {
+		Some((address, prefix)) => (address, Some(prefix)),
+		None => (value, None),
+	};
+
+	let address = IPv4::parse_with(address, mode).map_err(CidrError::AddressError)?;
+
+	let mask = match prefix {
+		None => IPv4Mask::new(32),
+		Some(prefix) if prefix.starts_with('/') => {
+			Err(IPv4MaskError::FormatError(prefix.to_string()))
+		}
+		Some(prefix) => IPv4Mask::parse_with(prefix, mode),
+	}
+	.map_err(CidrError::PrefixError)?;
+
+	Ok((address, mask))
+}
+
+impl TryFrom<&str> for Cidr {
+	type Error = CidrError;
+
+	/// Parses `a.b.c.d/n`, `a.b.c.d/w.x.y.z` or a bare `a.b.c.d` (treated as `/32`).
+	/// The prefix part accepts everything `IPv4Mask::try_from(&str)` does.
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		Cidr::parse_with(value, IPv4ParseMode::Lenient)
+	}
+}
+
+impl FromStr for Cidr {
+	type Err = CidrError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Cidr::try_from(s)
+	}
+}
+
+impl Prefix for Cidr {
+	type Address = IPv4;
+
+	fn contains(&self, address: &IPv4) -> bool {
+		Cidr::contains(self, address)
+	}
+
+	fn network(&self) -> IPv4 {
+		self.0
+	}
+
+	fn last(&self) -> IPv4 {
+		self.broadcast_address()
+	}
+
+	fn prefix_len(&self) -> u8 {
+		self.1.cidr()
+	}
+
+	fn overlaps(&self, other: &Cidr) -> bool {
+		Cidr::overlaps(self, other)
+	}
+
+	fn supernet(&self) -> Option<Cidr> {
+		Cidr::supernet(self)
+	}
+
+	fn halves(&self) -> Option<(Cidr, Cidr)> {
+		self.split().ok()
+	}
+}
+
+impl FamilyPrefix for Cidr {
+	const BITS: u8 = 32;
+
+	fn to_bits(address: &IPv4) -> u128 {
+		(address.to_u32() as u128) << 96
+	}
+
+	fn from_bits(bits: u128, prefix_len: u8) -> Cidr {
+		Cidr::from_host(
+			IPv4::from((bits >> 96) as u32),
+			IPv4Mask::new(prefix_len).unwrap(),
+		)
+	}
+}
+
+impl IntoIterator for Cidr {
+	type Item = IPv4;
+	type IntoIter = Addresses;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.addresses()
+	}
+}
+
+impl IntoIterator for &Cidr {
+	type Item = IPv4;
+	type IntoIter = Addresses;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.addresses()
+	}
+}
+
 impl Debug for Cidr {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		self.format(f)
@@ -58,25 +349,35 @@ impl Display for Cidr {
 
 #[cfg(test)]
 mod tests {
+	use std::collections::{BTreeSet, HashSet};
+	use std::net::{Ipv4Addr, SocketAddrV4};
+
 	use crate::cidr::Cidr;
 	use crate::cidr_error::CidrError;
 	use crate::ipv4::IPv4;
+	use crate::ipv4_error::Ipv4Error;
 	use crate::ipv4_mask::IPv4Mask;
+	use crate::ipv4_mask_error::IPv4MaskError;
+	use crate::ipv4_parse_mode::IPv4ParseMode;
 
 	fn assert_error<T>(result: Result<T, CidrError>, expected: CidrError) {
-		fn to_ordinal(e: CidrError) -> usize {
-			match e {
-				CidrError::InvalidAddressOrMask => 1,
-			}
+		match result {
+			Ok(_) => unreachable!(),
+			Err(e) => assert_eq!(e, expected),
 		}
+	}
 
-		if let Err(e) = result {
-			assert_eq!(to_ordinal(e), to_ordinal(expected));
-		} else {
-			unreachable!()
+	fn host_bits_set(address: &str, prefix: u8) -> CidrError {
+		CidrError::InvalidAddressOrMask {
+			address: IPv4::try_from(address).unwrap(),
+			mask: IPv4Mask::new(prefix).unwrap(),
 		}
 	}
 
+	fn format_error(input: &str) -> CidrError {
+		CidrError::PrefixError(IPv4MaskError::FormatError(input.to_string()))
+	}
+
 	#[test]
 	fn new_test() {
 		let fixture = Cidr::new(
@@ -92,7 +393,23 @@ mod tests {
 			IPv4Mask::new(24).unwrap(),
 		);
 
-		assert_error(fixture, CidrError::InvalidAddressOrMask);
+		assert_error(fixture, host_bits_set("192.168.10.1", 24));
+	}
+
+	#[test]
+	fn from_host_test() {
+		let fixture = Cidr::from_host(
+			IPv4::try_from("192.168.10.15").unwrap(),
+			IPv4Mask::new(24).unwrap(),
+		);
+		assert_eq!(fixture.address(), &IPv4::try_from("192.168.10.0").unwrap());
+		assert_eq!(fixture.subnet_mask(), &IPv4Mask::new(24).unwrap());
+
+		let fixture = Cidr::from_host(
+			IPv4::try_from("192.168.10.0").unwrap(),
+			IPv4Mask::new(24).unwrap(),
+		);
+		assert_eq!(fixture.address(), &IPv4::try_from("192.168.10.0").unwrap());
 	}
 
 	#[test]
@@ -168,4 +485,533 @@ mod tests {
 		assert_eq!(fixture.address(), actual.begin());
 		assert_eq!(&fixture.broadcast_address(), actual.end());
 	}
+
+	#[test]
+	fn try_from_str_test() {
+		let fixture = Cidr::try_from("192.168.10.0/24").unwrap();
+		assert_eq!(fixture.address(), &IPv4::try_from("192.168.10.0").unwrap());
+		assert_eq!(fixture.subnet_mask(), &IPv4Mask::new(24).unwrap());
+
+		let fixture = Cidr::try_from("192.168.10.0/255.255.255.0").unwrap();
+		assert_eq!(fixture.address(), &IPv4::try_from("192.168.10.0").unwrap());
+		assert_eq!(fixture.subnet_mask(), &IPv4Mask::new(24).unwrap());
+
+		let fixture = Cidr::try_from("192.168.10.15").unwrap();
+		assert_eq!(fixture.address(), &IPv4::try_from("192.168.10.15").unwrap());
+		assert_eq!(fixture.subnet_mask(), &IPv4Mask::new(32).unwrap());
+	}
+
+	#[test]
+	fn parse_with_test() {
+		let fixture = Cidr::parse_with("10.0.0.0/8", IPv4ParseMode::Strict).unwrap();
+		assert_eq!(fixture, Cidr::try_from("10.0.0.0/8").unwrap());
+
+		let fixture = Cidr::parse_with("0x7f.0/8", IPv4ParseMode::InetAton).unwrap();
+		assert_eq!(fixture, Cidr::try_from("127.0.0.0/8").unwrap());
+
+		assert_error(
+			Cidr::parse_with("010.0.0.0/8", IPv4ParseMode::Strict),
+			CidrError::AddressError(Ipv4Error::LeadingZero {
+				input: "010.0.0.0".to_string(),
+				position: 1,
+			}),
+		);
+
+		for prefix in ["08", "+8", "0xff000000", "255.000.0.0"] {
+			assert_error(
+				Cidr::parse_with(&format!("10.0.0.0/{}", prefix), IPv4ParseMode::Strict),
+				CidrError::PrefixError(IPv4MaskError::FormatError(prefix.to_string())),
+			);
+		}
+
+		let fixture = Cidr::parse_with("10.0.0.0/08", IPv4ParseMode::Lenient).unwrap();
+		assert_eq!(fixture, Cidr::try_from("10.0.0.0/8").unwrap());
+	}
+
+	#[test]
+	fn try_from_str_error_test() {
+		assert_error(
+			Cidr::try_from("192.168.10/24"),
+			CidrError::AddressError(Ipv4Error::InvalidFormat("192.168.10".to_string())),
+		);
+		assert_error(
+			Cidr::try_from("192.168.10.256/24"),
+			CidrError::AddressError(Ipv4Error::InvalidValue {
+				input: "192.168.10.256".to_string(),
+				position: 4,
+			}),
+		);
+		assert_error(Cidr::try_from("192.168.10.0/"), format_error(""));
+		assert_error(
+			Cidr::try_from("192.168.10.0/33"),
+			CidrError::PrefixError(IPv4MaskError::CidrOutOfRange(33)),
+		);
+		assert_error(
+			Cidr::try_from("192.168.10.0/255.0.255.0"),
+			CidrError::PrefixError(IPv4MaskError::InvalidSubnetMask(
+				IPv4::try_from("255.0.255.0").unwrap(),
+			)),
+		);
+		assert_error(
+			Cidr::try_from("192.168.10.0/255.255.0"),
+			format_error("255.255.0"),
+		);
+		assert_error(Cidr::try_from("192.168.10.0//24"), format_error("/24"));
+		assert_error(
+			Cidr::try_from("192.168.10.1/24"),
+			host_bits_set("192.168.10.1", 24),
+		);
+	}
+
+	#[test]
+	fn from_str_test() {
+		let fixture: Cidr = "10.0.0.0/8".parse().unwrap();
+		assert_eq!(format!("{}", fixture), "10.0.0.0/8");
+
+		assert_error("10.0.0.0/x".parse::<Cidr>(), format_error("x"));
+	}
+
+	#[test]
+	fn zero_prefix_test() {
+		let fixture = Cidr::try_from("0.0.0.0/0").unwrap();
+
+		assert!(fixture.contains(&IPv4::from(0)));
+		assert!(fixture.contains(&IPv4::try_from("192.168.10.120").unwrap()));
+		assert!(fixture.contains(&IPv4::from(0xffffffff)));
+
+		assert_eq!(fixture.broadcast_address(), IPv4::from(0xffffffff));
+
+		let actual = fixture.network_range();
+		assert_eq!(actual.begin(), &IPv4::from(0));
+		assert_eq!(actual.end(), &IPv4::from(0xffffffff));
+
+		assert_error(Cidr::try_from("10.0.0.0/0"), host_bits_set("10.0.0.0", 0));
+	}
+
+	#[test]
+	fn size_test() {
+		assert_eq!(Cidr::try_from("0.0.0.0/0").unwrap().size(), 1 << 32);
+		assert_eq!(Cidr::try_from("192.168.10.0/24").unwrap().size(), 256);
+		assert_eq!(Cidr::try_from("192.168.10.0/31").unwrap().size(), 2);
+		assert_eq!(Cidr::try_from("192.168.10.0/32").unwrap().size(), 1);
+	}
+
+	#[test]
+	fn usable_hosts_test() {
+		let fixture = Cidr::try_from("192.168.10.0/24").unwrap();
+		assert_eq!(fixture.usable_hosts(), 254);
+		assert_eq!(
+			fixture.first_host(),
+			IPv4::try_from("192.168.10.1").unwrap()
+		);
+		assert_eq!(
+			fixture.last_host(),
+			IPv4::try_from("192.168.10.254").unwrap()
+		);
+
+		let fixture = Cidr::try_from("192.168.10.4/30").unwrap();
+		assert_eq!(fixture.usable_hosts(), 2);
+		assert_eq!(
+			fixture.first_host(),
+			IPv4::try_from("192.168.10.5").unwrap()
+		);
+		assert_eq!(fixture.last_host(), IPv4::try_from("192.168.10.6").unwrap());
+
+		let fixture = Cidr::try_from("192.168.10.4/31").unwrap();
+		assert_eq!(fixture.usable_hosts(), 2);
+		assert_eq!(
+			fixture.first_host(),
+			IPv4::try_from("192.168.10.4").unwrap()
+		);
+		assert_eq!(fixture.last_host(), IPv4::try_from("192.168.10.5").unwrap());
+
+		let fixture = Cidr::try_from("192.168.10.4/32").unwrap();
+		assert_eq!(fixture.usable_hosts(), 1);
+		assert_eq!(
+			fixture.first_host(),
+			IPv4::try_from("192.168.10.4").unwrap()
+		);
+		assert_eq!(fixture.last_host(), IPv4::try_from("192.168.10.4").unwrap());
+
+		let fixture = Cidr::try_from("0.0.0.0/0").unwrap();
+		assert_eq!(fixture.usable_hosts(), (1 << 32) - 2);
+		assert_eq!(fixture.first_host(), IPv4::from(1));
+		assert_eq!(fixture.last_host(), IPv4::from(0xfffffffe));
+	}
+
+	#[test]
+	fn subnets_test() {
+		let fixture = Cidr::try_from("172.16.0.0/16").unwrap();
+
+		let actual: Vec<_> = fixture.subnets(24).unwrap().collect();
+		assert_eq!(actual.len(), 256);
+		assert_eq!(format!("{}", actual[0]), "172.16.0.0/24");
+		assert_eq!(format!("{}", actual[255]), "172.16.255.0/24");
+
+		let actual: Vec<_> = fixture.subnets(16).unwrap().collect();
+		assert_eq!(actual.len(), 1);
+		assert!(actual[0] == fixture);
+
+		let fixture = Cidr::try_from("0.0.0.0/0").unwrap();
+		let expected = match usize::try_from(1u64 << 32) {
+			Ok(count) => (count, Some(count)),
+			Err(_) => (usize::MAX, None),
+		};
+		assert_eq!(fixture.subnets(32).unwrap().size_hint(), expected);
+
+		assert_error(
+			fixture.subnets(33),
+			CidrError::PrefixError(IPv4MaskError::CidrOutOfRange(33)),
+		);
+
+		let fixture = Cidr::try_from("172.16.0.0/16").unwrap();
+		assert_error(
+			fixture.subnets(15),
+			CidrError::PrefixTooShort {
+				cidr: fixture,
+				prefix: 15,
+			},
+		);
+	}
+
+	#[test]
+	fn nth_subnet_test() {
+		let fixture = Cidr::try_from("192.168.10.0/24").unwrap();
+
+		let actual = fixture.nth_subnet(26, 2).unwrap();
+		assert_eq!(format!("{}", actual), "192.168.10.128/26");
+
+		let actual = fixture.nth_subnet(32, 255).unwrap();
+		assert_eq!(format!("{}", actual), "192.168.10.255/32");
+
+		assert_error(
+			fixture.nth_subnet(26, 4),
+			CidrError::SubnetIndexOutOfRange {
+				cidr: fixture,
+				prefix: 26,
+				index: 4,
+			},
+		);
+
+		let fixture = Cidr::try_from("0.0.0.0/0").unwrap();
+		let actual = fixture.nth_subnet(32, (1 << 32) - 1).unwrap();
+		assert_eq!(format!("{}", actual), "255.255.255.255/32");
+		assert_error(
+			fixture.nth_subnet(32, 1 << 32),
+			CidrError::SubnetIndexOutOfRange {
+				cidr: fixture,
+				prefix: 32,
+				index: 1 << 32,
+			},
+		);
+
+		let fixture = Cidr::try_from("192.168.10.0/24").unwrap();
+		assert_error(
+			fixture.nth_subnet(23, 0),
+			CidrError::PrefixTooShort {
+				cidr: fixture,
+				prefix: 23,
+			},
+		);
+		assert_error(
+			fixture.nth_subnet(33, 0),
+			CidrError::PrefixError(IPv4MaskError::CidrOutOfRange(33)),
+		);
+	}
+
+	#[test]
+	fn split_test() {
+		let fixture = Cidr::try_from("192.168.10.0/24").unwrap();
+		let (lower, upper) = fixture.split().unwrap();
+
+		assert_eq!(format!("{}", lower), "192.168.10.0/25");
+		assert_eq!(format!("{}", upper), "192.168.10.128/25");
+
+		let fixture = Cidr::try_from("0.0.0.0/0").unwrap();
+		let (lower, upper) = fixture.split().unwrap();
+
+		assert_eq!(format!("{}", lower), "0.0.0.0/1");
+		assert_eq!(format!("{}", upper), "128.0.0.0/1");
+
+		let fixture = Cidr::try_from("192.168.10.1/32").unwrap();
+		assert_error(
+			fixture.split(),
+			CidrError::PrefixError(IPv4MaskError::CidrOutOfRange(33)),
+		);
+	}
+
+	#[test]
+	fn supernet_test() {
+		let fixture = Cidr::try_from("192.168.10.128/25").unwrap();
+		let actual = fixture.supernet().unwrap();
+		assert_eq!(format!("{}", actual), "192.168.10.0/24");
+
+		let fixture = Cidr::try_from("128.0.0.0/1").unwrap();
+		let actual = fixture.supernet().unwrap();
+		assert_eq!(format!("{}", actual), "0.0.0.0/0");
+
+		let fixture = Cidr::try_from("0.0.0.0/0").unwrap();
+		assert!(fixture.supernet().is_none());
+	}
+
+	#[test]
+	fn supernet_at_test() {
+		let fixture = Cidr::try_from("192.168.10.128/25").unwrap();
+
+		let actual = fixture.supernet_at(16).unwrap();
+		assert_eq!(format!("{}", actual), "192.168.0.0/16");
+
+		let actual = fixture.supernet_at(25).unwrap();
+		assert!(actual == fixture);
+
+		let actual = fixture.supernet_at(0).unwrap();
+		assert_eq!(format!("{}", actual), "0.0.0.0/0");
+
+		assert_error(
+			fixture.supernet_at(26),
+			CidrError::PrefixTooLong {
+				cidr: fixture,
+				prefix: 26,
+			},
+		);
+		assert_error(
+			fixture.supernet_at(33),
+			CidrError::PrefixError(IPv4MaskError::CidrOutOfRange(33)),
+		);
+	}
+
+	#[test]
+	fn sibling_test() {
+		let fixture = Cidr::try_from("192.168.10.128/25").unwrap();
+		let actual = fixture.sibling().unwrap();
+		assert_eq!(format!("{}", actual), "192.168.10.0/25");
+		assert!(actual.sibling().unwrap() == fixture);
+
+		let fixture = Cidr::try_from("10.0.0.1/32").unwrap();
+		let actual = fixture.sibling().unwrap();
+		assert_eq!(format!("{}", actual), "10.0.0.0/32");
+
+		let fixture = Cidr::try_from("0.0.0.0/0").unwrap();
+		assert!(fixture.sibling().is_none());
+	}
+
+	#[test]
+	fn is_adjacent_test() {
+		let fixture = Cidr::try_from("192.168.10.0/25").unwrap();
+
+		assert!(fixture.is_adjacent(&Cidr::try_from("192.168.10.128/25").unwrap()));
+		assert!(!fixture.is_adjacent(&fixture));
+		assert!(!fixture.is_adjacent(&Cidr::try_from("192.168.10.128/26").unwrap()));
+
+		// Contiguous, but they do not share a /24 supernet.
+		let fixture = Cidr::try_from("192.168.10.128/25").unwrap();
+		assert!(!fixture.is_adjacent(&Cidr::try_from("192.168.11.0/25").unwrap()));
+
+		let fixture = Cidr::try_from("0.0.0.0/0").unwrap();
+		assert!(!fixture.is_adjacent(&fixture));
+	}
+
+	#[test]
+	fn contains_cidr_test() {
+		let fixture = Cidr::try_from("10.0.0.0/8").unwrap();
+
+		assert!(fixture.contains_cidr(&Cidr::try_from("10.20.0.0/16").unwrap()));
+		assert!(fixture.contains_cidr(&fixture));
+		assert!(!fixture.contains_cidr(&Cidr::try_from("11.0.0.0/16").unwrap()));
+		assert!(!fixture.contains_cidr(&Cidr::try_from("0.0.0.0/0").unwrap()));
+		assert!(Cidr::try_from("0.0.0.0/0").unwrap().contains_cidr(&fixture));
+	}
+
+	#[test]
+	fn overlaps_test() {
+		let fixture = Cidr::try_from("10.0.0.0/8").unwrap();
+
+		assert!(fixture.overlaps(&Cidr::try_from("10.20.0.0/16").unwrap()));
+		assert!(fixture.overlaps(&Cidr::try_from("0.0.0.0/0").unwrap()));
+		assert!(fixture.overlaps(&fixture));
+		assert!(!fixture.overlaps(&Cidr::try_from("11.0.0.0/8").unwrap()));
+	}
+
+	#[test]
+	fn subnet_and_supernet_test() {
+		let outer = Cidr::try_from("10.0.0.0/8").unwrap();
+		let inner = Cidr::try_from("10.20.0.0/16").unwrap();
+		let other = Cidr::try_from("192.168.0.0/16").unwrap();
+
+		assert!(inner.is_subnet_of(&outer));
+		assert!(!outer.is_subnet_of(&inner));
+		assert!(!other.is_subnet_of(&outer));
+
+		assert!(outer.is_supernet_of(&inner));
+		assert!(!inner.is_supernet_of(&outer));
+		assert!(!outer.is_supernet_of(&other));
+
+		assert!(outer.is_subnet_of(&outer));
+		assert!(outer.is_supernet_of(&outer));
+	}
+
+	#[test]
+	fn intersection_test() {
+		let outer = Cidr::try_from("10.0.0.0/8").unwrap();
+		let inner = Cidr::try_from("10.20.0.0/16").unwrap();
+		let other = Cidr::try_from("192.168.0.0/16").unwrap();
+
+		assert!(outer.intersection(&inner).unwrap() == inner);
+		assert!(inner.intersection(&outer).unwrap() == inner);
+		assert!(outer.intersection(&other).is_none());
+	}
+
+	#[test]
+	fn ord_test() {
+		let mut actual: Vec<_> = [
+			"192.168.10.0/24",
+			"10.0.0.0/16",
+			"10.0.0.0/8",
+			"172.16.0.0/12",
+			"10.0.0.0/8",
+		]
+		.iter()
+		.map(|s| Cidr::try_from(*s).unwrap())
+		.collect();
+
+		actual.sort();
+		actual.dedup();
+
+		let actual: Vec<_> = actual.iter().map(|c| format!("{}", c)).collect();
+		assert_eq!(
+			actual,
+			[
+				"10.0.0.0/8",
+				"10.0.0.0/16",
+				"172.16.0.0/12",
+				"192.168.10.0/24"
+			]
+		);
+	}
+
+	#[test]
+	fn collection_test() {
+		let a = Cidr::try_from("10.0.0.0/8").unwrap();
+		let b = Cidr::try_from("10.0.0.0/16").unwrap();
+
+		let set: BTreeSet<_> = [b, a, b].into_iter().collect();
+		assert_eq!(set.into_iter().collect::<Vec<_>>(), [a, b]);
+
+		let set: HashSet<_> = [a, b, a].into_iter().collect();
+		assert_eq!(set.len(), 2);
+		assert!(set.contains(&a));
+	}
+
+	fn exclude(cidr: &str, other: &str) -> Vec<String> {
+		Cidr::try_from(cidr)
+			.unwrap()
+			.exclude(&Cidr::try_from(other).unwrap())
+			.iter()
+			.map(|c| format!("{}", c))
+			.collect()
+	}
+
+	#[test]
+	fn exclude_test() {
+		assert_eq!(
+			exclude("192.168.10.0/24", "192.168.10.64/26"),
+			["192.168.10.0/26", "192.168.10.128/25"]
+		);
+		assert_eq!(
+			exclude("10.0.0.0/29", "10.0.0.3/32"),
+			["10.0.0.0/31", "10.0.0.2/32", "10.0.0.4/30"]
+		);
+		assert_eq!(exclude("10.0.0.0/8", "10.0.0.0/8"), Vec::<String>::new());
+		assert_eq!(exclude("10.1.0.0/16", "10.0.0.0/8"), Vec::<String>::new());
+		assert_eq!(exclude("10.0.0.0/8", "11.0.0.0/8"), ["10.0.0.0/8"]);
+		assert_eq!(exclude("0.0.0.0/0", "0.0.0.0/32").len(), 32);
+	}
+
+	#[test]
+	fn addresses_test() {
+		let fixture = Cidr::try_from("192.168.10.0/30").unwrap();
+
+		let actual: Vec<_> = fixture.addresses().map(|a| format!("{}", a)).collect();
+		assert_eq!(
+			actual,
+			[
+				"192.168.10.0",
+				"192.168.10.1",
+				"192.168.10.2",
+				"192.168.10.3"
+			]
+		);
+
+		let actual: Vec<_> = fixture.into_iter().collect();
+		assert_eq!(actual.len(), 4);
+		assert_eq!((&fixture).into_iter().remaining(), 4);
+
+		let fixture = Cidr::try_from("0.0.0.0/0").unwrap();
+		assert_eq!(fixture.addresses().remaining(), 1 << 32);
+		assert_eq!(
+			fixture.addresses().nth(0xc0a8_0a00),
+			Some(IPv4::try_from("192.168.10.0").unwrap())
+		);
+	}
+
+	#[test]
+	fn hosts_test() {
+		let fixture = Cidr::try_from("192.168.10.0/24").unwrap();
+		let actual = fixture.hosts();
+		assert_eq!(actual.remaining(), 254);
+
+		let actual: Vec<_> = fixture
+			.hosts()
+			.step_by(100)
+			.map(|a| format!("{}", a))
+			.collect();
+		assert_eq!(actual, ["192.168.10.1", "192.168.10.101", "192.168.10.201"]);
+
+		let fixture = Cidr::try_from("192.168.10.0/31").unwrap();
+		assert_eq!(fixture.hosts().remaining(), 2);
+
+		let fixture = Cidr::try_from("192.168.10.1/32").unwrap();
+		let actual: Vec<_> = fixture.hosts().collect();
+		assert_eq!(actual, [IPv4::try_from("192.168.10.1").unwrap()]);
+	}
+
+	#[test]
+	fn special_purpose_test() {
+		let special = |value: &str| -> Vec<String> {
+			Cidr::try_from(value)
+				.unwrap()
+				.special_purpose()
+				.iter()
+				.map(|e| format!("{}", e.cidr()))
+				.collect()
+		};
+
+		assert_eq!(special("10.20.0.0/16"), ["10.0.0.0/8"]);
+		assert_eq!(
+			special("192.0.0.0/16"),
+			[
+				"192.0.0.0/24",
+				"192.0.0.0/29",
+				"192.0.0.8/32",
+				"192.0.0.9/32",
+				"192.0.0.10/32",
+				"192.0.0.170/32",
+				"192.0.0.171/32",
+				"192.0.2.0/24"
+			]
+		);
+		assert_eq!(special("0.0.0.0/0").len(), 23);
+		assert!(special("8.8.0.0/16").is_empty());
+	}
+
+	#[test]
+	fn contains_socket_test() {
+		let fixture = Cidr::try_from("192.168.10.0/24").unwrap();
+
+		let socket = SocketAddrV4::new(Ipv4Addr::new(192, 168, 10, 15), 443);
+		assert!(fixture.contains_socket(&socket));
+
+		let socket = SocketAddrV4::new(Ipv4Addr::new(192, 168, 11, 15), 443);
+		assert!(!fixture.contains_socket(&socket));
+	}
 }
@@ -0,0 +1,305 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::cidr6_error::Cidr6Error;
+use crate::ipv6::IPv6;
+use crate::ipv6_mask::IPv6Mask;
+use crate::ipv6_mask_error::IPv6MaskError;
+use crate::ipv6_network_range::IPv6NetworkRange;
+use crate::prefix::{FamilyPrefix, Prefix};
+
+/// Ordered by network address, then by prefix length.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub struct Cidr6(IPv6, IPv6Mask);
+
+impl Cidr6 {
+	pub fn new(address: IPv6, subnet: IPv6Mask) -> Result<Cidr6, Cidr6Error> {
+		if subnet.network_address(&address) != address {
+			Err(Cidr6Error::InvalidAddressOrMask {
+				address,
+				mask: subnet,
+			})
+		} else {
+			Ok(Cidr6(address, subnet))
+		}
+	}
+
+	pub fn from_host(address: IPv6, subnet: IPv6Mask) -> Cidr6 {
+		Cidr6(subnet.network_address(&address), subnet)
+	}
+
+	pub fn contains(&self, address: &IPv6) -> bool {
+		self.1.network_address(address) == self.0
+	}
+
+	pub fn contains_cidr(&self, other: &Cidr6) -> bool {
+		self.1.cidr() <= other.1.cidr() && self.contains(&other.0)
+	}
+
+	pub fn overlaps(&self, other: &Cidr6) -> bool {
+		self.contains_cidr(other) || other.contains_cidr(self)
+	}
+
+	pub fn network_range(&self) -> IPv6NetworkRange {
+		IPv6NetworkRange::new(self.0, self.last_address()).unwrap()
+	}
+
+	pub fn address(&self) -> &IPv6 {
+		&self.0
+	}
+
+	pub fn subnet_mask(&self) -> &IPv6Mask {
+		&self.1
+	}
+
+	pub fn last_address(&self) -> IPv6 {
+		self.1.last_address(&self.0)
+	}
+
+	pub fn supernet(&self) -> Option<Cidr6> {
+		match self.1.cidr() {
+			0 => None,
+			prefix => Some(Cidr6::from_host(self.0, IPv6Mask::new(prefix - 1).unwrap())),
+		}
+	}
+
+	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}{}", self.0, self.1)
+	}
+}
+
+impl TryFrom<&str> for Cidr6 {
+	type Error = Cidr6Error;
+
+	/// Parses `x::/n` or a bare address (treated as `/128`).
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		let (address, prefix) = match value.split_once('/') {
+			Some((address, prefix)) => (address, Some(prefix)),
+			None => (value, None),
+		};
+
+		let address = IPv6::try_from(address).map_err(Cidr6Error::AddressError)?;
+
+		let mask = match prefix {
+			None => IPv6Mask::new(128),
+			Some(prefix) if prefix.starts_with('/') => {
+				Err(IPv6MaskError::FormatError(prefix.to_string()))
+			}
+			Some(prefix) => IPv6Mask::try_from(prefix),
+		}
+		.map_err(Cidr6Error::PrefixError)?;
+
+		Cidr6::new(address, mask)
+	}
+}
+
+impl FromStr for Cidr6 {
+	type Err = Cidr6Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Cidr6::try_from(s)
+	}
+}
+
+impl Prefix for Cidr6 {
+	type Address = IPv6;
+
+	fn contains(&self, address: &IPv6) -> bool {
+		Cidr6::contains(self, address)
+	}
+
+	fn network(&self) -> IPv6 {
+		self.0
+	}
+
+	fn last(&self) -> IPv6 {
+		self.last_address()
+	}
+
+	fn prefix_len(&self) -> u8 {
+		self.1.cidr()
+	}
+
+	fn overlaps(&self, other: &Cidr6) -> bool {
+		Cidr6::overlaps(self, other)
+	}
+
+	fn supernet(&self) -> Option<Cidr6> {
+		Cidr6::supernet(self)
+	}
+
+	fn halves(&self) -> Option<(Cidr6, Cidr6)> {
+		let prefix = self.1.cidr();
+		if prefix == 128 {
+			return None;
+		}
+
+		let mask = IPv6Mask::new(prefix + 1).unwrap();
+		let upper = IPv6::from(self.0.to_u128() | (1u128 << (127 - prefix)));
+		Some((
+			Cidr6::from_host(self.0, mask),
+			Cidr6::from_host(upper, mask),
+		))
+	}
+}
+
+impl FamilyPrefix for Cidr6 {
+	const BITS: u8 = 128;
+
+	fn to_bits(address: &IPv6) -> u128 {
+		address.to_u128()
+	}
+
+	fn from_bits(bits: u128, prefix_len: u8) -> Cidr6 {
+		Cidr6::from_host(IPv6::from(bits), IPv6Mask::new(prefix_len).unwrap())
+	}
+}
+
+impl Debug for Cidr6 {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+impl Display for Cidr6 {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::cidr6::Cidr6;
+	use crate::cidr6_error::Cidr6Error;
+	use crate::ipv6::IPv6;
+	use crate::ipv6_error::Ipv6Error;
+	use crate::ipv6_mask::IPv6Mask;
+	use crate::ipv6_mask_error::IPv6MaskError;
+
+	fn assert_error<T>(result: Result<T, Cidr6Error>, expected: Cidr6Error) {
+		match result {
+			Ok(_) => unreachable!(),
+			Err(e) => assert_eq!(e, expected),
+		}
+	}
+
+	fn host_bits_set(address: &str, prefix: u8) -> Cidr6Error {
+		Cidr6Error::InvalidAddressOrMask {
+			address: ip(address),
+			mask: IPv6Mask::new(prefix).unwrap(),
+		}
+	}
+
+	fn ip(value: &str) -> IPv6 {
+		IPv6::try_from(value).unwrap()
+	}
+
+	fn cidr(value: &str) -> Cidr6 {
+		Cidr6::try_from(value).unwrap()
+	}
+
+	#[test]
+	fn new_test() {
+		let actual = Cidr6::new(ip("2001:db8::"), IPv6Mask::new(32).unwrap()).unwrap();
+		assert_eq!(actual.address(), &ip("2001:db8::"));
+		assert_eq!(actual.subnet_mask().cidr(), 32);
+
+		assert_error(
+			Cidr6::new(ip("2001:db8::1"), IPv6Mask::new(32).unwrap()),
+			host_bits_set("2001:db8::1", 32),
+		);
+	}
+
+	#[test]
+	fn from_host_test() {
+		let actual = Cidr6::from_host(ip("2001:db8::1"), IPv6Mask::new(64).unwrap());
+		assert_eq!(actual, cidr("2001:db8::/64"));
+	}
+
+	#[test]
+	fn contains_test() {
+		let fixture = cidr("2001:db8::/32");
+
+		assert!(fixture.contains(&ip("2001:db8::")));
+		assert!(fixture.contains(&ip("2001:db8:ffff::1")));
+		assert!(!fixture.contains(&ip("2001:db9::")));
+		assert!(!fixture.contains(&ip("::ffff:192.0.2.1")));
+
+		assert!(cidr("::/0").contains(&ip("ffff::")));
+	}
+
+	#[test]
+	fn contains_cidr_test() {
+		let fixture = cidr("2001:db8::/32");
+
+		assert!(fixture.contains_cidr(&cidr("2001:db8:1::/48")));
+		assert!(fixture.contains_cidr(&fixture));
+		assert!(!fixture.contains_cidr(&cidr("2001::/16")));
+		assert!(fixture.overlaps(&cidr("2001::/16")));
+		assert!(!fixture.overlaps(&cidr("2001:db9::/32")));
+	}
+
+	#[test]
+	fn last_address_test() {
+		let fixture = cidr("2001:db8::/64");
+		assert_eq!(fixture.last_address(), ip("2001:db8::ffff:ffff:ffff:ffff"));
+
+		let range = fixture.network_range();
+		assert_eq!(range.begin(), &ip("2001:db8::"));
+		assert_eq!(range.end(), &ip("2001:db8::ffff:ffff:ffff:ffff"));
+	}
+
+	#[test]
+	fn supernet_test() {
+		assert_eq!(
+			cidr("2001:db8::/32").supernet(),
+			Some(cidr("2001:db8::/31"))
+		);
+		assert_eq!(
+			cidr("2001:db9::/32").supernet(),
+			Some(cidr("2001:db8::/31"))
+		);
+		assert_eq!(cidr("::/0").supernet(), None);
+	}
+
+	#[test]
+	fn try_from_str_test() {
+		assert_eq!(cidr("2001:db8::1").subnet_mask().cidr(), 128);
+		assert_eq!(cidr("::/0").subnet_mask().cidr(), 0);
+		assert_eq!(cidr("fe80::/ffc0::").subnet_mask().cidr(), 10);
+		assert_eq!(
+			"2001:db8::/32".parse::<Cidr6>().unwrap(),
+			cidr("2001:db8::/32")
+		);
+
+		assert_error(
+			Cidr6::try_from("2001:db8::1/32"),
+			host_bits_set("2001:db8::1", 32),
+		);
+		assert_error(
+			Cidr6::try_from("2001:db8:::/32"),
+			Cidr6Error::AddressError(Ipv6Error::InvalidFormat("2001:db8:::".to_string())),
+		);
+		assert_error(
+			Cidr6::try_from("2001:db8::/129"),
+			Cidr6Error::PrefixError(IPv6MaskError::CidrOutOfRange(129)),
+		);
+		assert_error(
+			Cidr6::try_from("2001:db8:://32"),
+			Cidr6Error::PrefixError(IPv6MaskError::FormatError("/32".to_string())),
+		);
+	}
+
+	#[test]
+	fn display_test() {
+		let fixture = cidr("2001:0DB8:0000::/48");
+		assert_eq!(format!("{}", fixture), "2001:db8::/48");
+		assert_eq!(format!("{:?}", fixture), "2001:db8::/48");
+	}
+
+	#[test]
+	fn ord_test() {
+		assert!(cidr("2001:db8::/32") < cidr("2001:db8::/48"));
+		assert!(cidr("2001:db8::/48") < cidr("2001:db9::/32"));
+	}
+}
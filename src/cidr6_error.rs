@@ -0,0 +1,75 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::ipv6::IPv6;
+use crate::ipv6_error::Ipv6Error;
+use crate::ipv6_mask::IPv6Mask;
+use crate::ipv6_mask_error::IPv6MaskError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cidr6Error {
+	InvalidAddressOrMask { address: IPv6, mask: IPv6Mask },
+	AddressError(Ipv6Error),
+	PrefixError(IPv6MaskError),
+}
+
+impl Display for Cidr6Error {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Cidr6Error::InvalidAddressOrMask { address, mask } => write!(
+				f,
+				"{}{} has host bits set (the network is {}{})",
+				address,
+				mask,
+				mask.network_address(address),
+				mask
+			),
+			Cidr6Error::AddressError(e) => write!(f, "{}", e),
+			Cidr6Error::PrefixError(e) => write!(f, "{}", e),
+		}
+	}
+}
+
+/// Like `CidrError`, the wrapping variants are transparent.
+impl Error for Cidr6Error {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Cidr6Error::AddressError(e) => e.source(),
+			Cidr6Error::PrefixError(e) => e.source(),
+			_ => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::cidr6_error::Cidr6Error;
+	use crate::ipv6::IPv6;
+	use crate::ipv6_error::Ipv6Error;
+	use crate::ipv6_mask::IPv6Mask;
+	use crate::ipv6_mask_error::IPv6MaskError;
+
+	#[test]
+	fn display_test() {
+		let fixture = Cidr6Error::InvalidAddressOrMask {
+			address: IPv6::try_from("2001:db8::1").unwrap(),
+			mask: IPv6Mask::new(32).unwrap(),
+		};
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"2001:db8::1/32 has host bits set (the network is 2001:db8::/32)"
+		);
+
+		let fixture = Cidr6Error::AddressError(Ipv6Error::InvalidFormat("1::2::3".to_string()));
+		let actual = format!("{}", fixture);
+		assert_eq!(actual, "\"1::2::3\" is not an IPv6 address");
+
+		let fixture = Cidr6Error::PrefixError(IPv6MaskError::CidrOutOfRange(129));
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"prefix length 129 is out of range (expected 0 to 128)"
+		);
+	}
+}
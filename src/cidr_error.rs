@@ -1,45 +1,178 @@
-use std::fmt::{Debug, Display, Formatter};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
-pub enum CidrError {
-	InvalidAddressOrMask,
-}
-
-impl CidrError {
-	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		let scr = match self {
-			CidrError::InvalidAddressOrMask => "CidrError::InvalidAddressOrMask",
-		};
+use crate::cidr::Cidr;
+use crate::ipv4::IPv4;
+use crate::ipv4_error::Ipv4Error;
+use crate::ipv4_mask::IPv4Mask;
+use crate::ipv4_mask_error::IPv4MaskError;
 
-		write!(f, "{}", scr)
-	}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CidrError {
+	InvalidAddressOrMask { address: IPv4, mask: IPv4Mask },
+	AddressError(Ipv4Error),
+	PrefixError(IPv4MaskError),
+	PrefixTooShort { cidr: Cidr, prefix: u8 },
+	PrefixTooLong { cidr: Cidr, prefix: u8 },
+	SubnetIndexOutOfRange { cidr: Cidr, prefix: u8, index: u64 },
 }
 
-impl Debug for CidrError {
+impl Display for CidrError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		self.format(f)
+		match self {
+			CidrError::InvalidAddressOrMask { address, mask } => write!(
+				f,
+				"{}{} has host bits set (the network is {}{})",
+				address,
+				mask,
+				mask.network_address(address),
+				mask
+			),
+			CidrError::AddressError(e) => write!(f, "{}", e),
+			CidrError::PrefixError(e) => write!(f, "{}", e),
+			CidrError::PrefixTooShort { cidr, prefix } => write!(
+				f,
+				"{} has no /{} subnets: /{} is shorter than the block itself",
+				cidr, prefix, prefix
+			),
+			CidrError::PrefixTooLong { cidr, prefix } => write!(
+				f,
+				"{} has no /{} supernet: /{} is longer than the block itself",
+				cidr, prefix, prefix
+			),
+			CidrError::SubnetIndexOutOfRange {
+				cidr,
+				prefix,
+				index,
+			} => match prefix
+				.checked_sub(cidr.subnet_mask().cidr())
+				.and_then(|bits| 1u64.checked_shl(bits as u32))
+			{
+				Some(count) => write!(
+					f,
+					"{} has {} /{} subnets, so index {} is out of range",
+					cidr, count, prefix, index
+				),
+				None => write!(
+					f,
+					"subnet index {} of {} at /{} is out of range",
+					index, cidr, prefix
+				),
+			},
+		}
 	}
 }
 
-impl Display for CidrError {
-	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		self.format(f)
+/// `AddressError` and `PrefixError` are transparent: they display as, and
+/// share the source of, the error they wrap.
+impl Error for CidrError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			CidrError::AddressError(e) => e.source(),
+			CidrError::PrefixError(e) => e.source(),
+			_ => None,
+		}
 	}
 }
 
 #[cfg(test)]
 mod tests {
+	use crate::cidr::Cidr;
 	use crate::cidr_error::CidrError;
-	#[test]
-	fn debug_test() {
-		let fixture = CidrError::InvalidAddressOrMask;
-		let actual = format!("{:?}", fixture);
-		assert_eq!(actual, "CidrError::InvalidAddressOrMask");
+	use crate::ipv4::IPv4;
+	use crate::ipv4_error::Ipv4Error;
+	use crate::ipv4_mask::IPv4Mask;
+	use crate::ipv4_mask_error::IPv4MaskError;
+
+	fn cidr(value: &str) -> Cidr {
+		Cidr::try_from(value).unwrap()
 	}
 
 	#[test]
 	fn display_test() {
-		let fixture = CidrError::InvalidAddressOrMask;
-		let actual = format!("{:}", fixture);
-		assert_eq!(actual, "CidrError::InvalidAddressOrMask");
+		let fixture = CidrError::InvalidAddressOrMask {
+			address: IPv4::try_from("10.0.0.1").unwrap(),
+			mask: IPv4Mask::new(8).unwrap(),
+		};
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"10.0.0.1/8 has host bits set (the network is 10.0.0.0/8)"
+		);
+
+		let fixture = CidrError::AddressError(Ipv4Error::InvalidFormat("10.0".to_string()));
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"\"10.0\" is not an IPv4 address: expected four dot-separated octets"
+		);
+
+		let fixture = CidrError::PrefixError(IPv4MaskError::CidrOutOfRange(40));
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"prefix length 40 is out of range (expected 0 to 32)"
+		);
+
+		let fixture = CidrError::PrefixTooShort {
+			cidr: cidr("10.0.0.0/24"),
+			prefix: 16,
+		};
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"10.0.0.0/24 has no /16 subnets: /16 is shorter than the block itself"
+		);
+
+		let fixture = CidrError::PrefixTooLong {
+			cidr: cidr("10.0.0.0/24"),
+			prefix: 28,
+		};
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"10.0.0.0/24 has no /28 supernet: /28 is longer than the block itself"
+		);
+
+		let fixture = CidrError::SubnetIndexOutOfRange {
+			cidr: cidr("10.0.0.0/24"),
+			prefix: 26,
+			index: 4,
+		};
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"10.0.0.0/24 has 4 /26 subnets, so index 4 is out of range"
+		);
+
+		let fixture = CidrError::SubnetIndexOutOfRange {
+			cidr: cidr("10.0.0.0/24"),
+			prefix: 16,
+			index: 4,
+		};
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"subnet index 4 of 10.0.0.0/24 at /16 is out of range"
+		);
+
+		let fixture = CidrError::SubnetIndexOutOfRange {
+			cidr: cidr("0.0.0.0/0"),
+			prefix: 200,
+			index: 4,
+		};
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"subnet index 4 of 0.0.0.0/0 at /200 is out of range"
+		);
+	}
+
+	#[test]
+	fn error_test() {
+		let fixture: Box<dyn std::error::Error> = Box::new(CidrError::AddressError(
+			Ipv4Error::InvalidFormat("10.0".to_string()),
+		));
+		assert!(fixture.source().is_none());
 	}
 }
@@ -0,0 +1,275 @@
+use crate::prefix::Prefix;
+
+/// Collapses `cidrs` into the smallest equivalent set of blocks: nested blocks
+/// are dropped and sibling halves are merged into their supernet repeatedly.
+/// The result is sorted. Works for any `Prefix`, so a list of `IpCidr` may mix
+/// both families.
+///
+/// ```
+/// use cidr_checker::{cidr_list, IpCidr};
+///
+/// let cidrs = ["10.0.0.0/25", "10.0.0.128/25", "10.0.0.7/32", "2001:db8::/33"]
+///     .into_iter()
+///     .map(IpCidr::try_from)
+///     .collect::<Result<Vec<_>, _>>()?;
+///
+/// let actual: Vec<_> = cidr_list::aggregate(cidrs).iter().map(|c| c.to_string()).collect();
+/// assert_eq!(actual, ["10.0.0.0/24", "2001:db8::/33"]);
+/// # Ok::<(), cidr_checker::Error>(())
+/// ```
+pub fn aggregate<P: Prefix, T: IntoIterator<Item = P>>(cidrs: T) -> Vec<P> {
+	let mut sorted: Vec<_> = cidrs.into_iter().collect();
+	sorted.sort();
+
+	let mut result: Vec<P> = Vec::with_capacity(sorted.len());
+
+	for cidr in sorted {
+		if result
+			.last()
+			.is_some_and(|last| last.contains_prefix(&cidr))
+		{
+			continue;
+		}
+
+		result.push(cidr);
+
+		while result.len() >= 2 {
+			let upper = result[result.len() - 1];
+			let lower = result[result.len() - 2];
+
+			let supernet = lower.supernet();
+			if supernet.is_none() || supernet != upper.supernet() {
+				break;
+			}
+
+			result.truncate(result.len() - 2);
+			result.push(supernet.unwrap());
+		}
+	}
+
+	result
+}
+
+/// The minimal, sorted list of blocks covering every address in `cidrs` that
+/// is not in `excluded`. Like `aggregate`, works for any `Prefix`, and blocks
+/// of one family never remove addresses of the other.
+pub fn difference<P, T, U>(cidrs: T, excluded: U) -> Vec<P>
+where
+	P: Prefix,
+	T: IntoIterator<Item = P>,
+	U: IntoIterator<Item = P>,
+{
+	let excluded = aggregate(excluded);
+	let mut result = Vec::new();
+
+	for cidr in aggregate(cidrs) {
+		let mut remaining = vec![cidr];
+
+		for other in excluded.iter().filter(|other| other.overlaps(&cidr)) {
+			remaining = remaining
+				.iter()
+				.flat_map(|remain| exclude(*remain, other))
+				.collect();
+		}
+
+		result.extend(remaining);
+	}
+
+	aggregate(result)
+}
+
+/// `cidr` without the addresses of `other`: the halves split off on the way
+/// down from `cidr` to `other`.
+pub(crate) fn exclude<P: Prefix>(cidr: P, other: &P) -> Vec<P> {
+	if other.contains_prefix(&cidr) {
+		return Vec::new();
+	}
+
+	if !cidr.contains_prefix(other) {
+		return vec![cidr];
+	}
+
+	let mut result = Vec::new();
+	let mut current = cidr;
+
+	while current != *other {
+		let (lower, upper) = current.halves().unwrap();
+
+		if lower.contains_prefix(other) {
+			result.push(upper);
+			current = lower;
+		} else {
+			result.push(lower);
+			current = upper;
+		}
+	}
+
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::cidr::Cidr;
+	use crate::cidr_list::{aggregate, difference};
+	use crate::ip_cidr::IpCidr;
+
+	fn cidrs(values: &[&str]) -> Vec<Cidr> {
+		values.iter().map(|s| Cidr::try_from(*s).unwrap()).collect()
+	}
+
+	fn aggregate_str(values: &[&str]) -> Vec<String> {
+		aggregate(cidrs(values))
+			.iter()
+			.map(|c| format!("{}", c))
+			.collect()
+	}
+
+	#[test]
+	fn aggregate_nested_test() {
+		assert_eq!(
+			aggregate_str(&["10.0.0.0/16", "10.0.0.0/8", "10.20.30.0/24", "10.0.0.0/8"]),
+			["10.0.0.0/8"]
+		);
+	}
+
+	#[test]
+	fn aggregate_siblings_test() {
+		assert_eq!(
+			aggregate_str(&[
+				"192.168.10.192/26",
+				"192.168.10.0/26",
+				"192.168.10.128/26",
+				"192.168.10.64/26",
+			]),
+			["192.168.10.0/24"]
+		);
+
+		assert_eq!(
+			aggregate_str(&["192.168.10.0/25", "192.168.10.128/26", "192.168.10.192/27"]),
+			["192.168.10.0/25", "192.168.10.128/26", "192.168.10.192/27"]
+		);
+	}
+
+	#[test]
+	fn aggregate_cascade_test() {
+		assert_eq!(
+			aggregate_str(&[
+				"10.0.0.0/25",
+				"10.0.0.128/26",
+				"10.0.0.192/26",
+				"10.0.1.0/24"
+			]),
+			["10.0.0.0/23"]
+		);
+
+		assert_eq!(
+			aggregate_str(&["0.0.0.0/1", "128.0.0.0/2", "192.0.0.0/2"]),
+			["0.0.0.0/0"]
+		);
+	}
+
+	#[test]
+	fn aggregate_disjoint_test() {
+		assert_eq!(
+			aggregate_str(&["192.168.11.0/24", "192.168.10.128/25", "10.0.0.1/32"]),
+			["10.0.0.1/32", "192.168.10.128/25", "192.168.11.0/24"]
+		);
+
+		assert!(aggregate(Vec::<Cidr>::new()).is_empty());
+	}
+
+	fn difference_str(values: &[&str], excluded: &[&str]) -> Vec<String> {
+		difference(cidrs(values), cidrs(excluded))
+			.iter()
+			.map(|c| format!("{}", c))
+			.collect()
+	}
+
+	#[test]
+	fn difference_test() {
+		let actual = difference_str(&["10.0.0.0/8"], &["10.20.0.0/16", "10.30.5.0/24"]);
+
+		assert_eq!(actual.len(), 18);
+		assert!(actual.contains(&"10.0.0.0/12".to_string()));
+		assert!(actual.contains(&"10.21.0.0/16".to_string()));
+		assert!(actual.contains(&"10.30.4.0/24".to_string()));
+		assert!(actual.contains(&"10.30.6.0/23".to_string()));
+		assert!(!actual.iter().any(|c| c.starts_with("10.20.")));
+
+		let remaining: u64 = difference(
+			cidrs(&["10.0.0.0/8"]),
+			cidrs(&["10.20.0.0/16", "10.30.5.0/24"]),
+		)
+		.iter()
+		.map(|c| c.size())
+		.sum();
+		assert_eq!(remaining, (1 << 24) - (1 << 16) - (1 << 8));
+	}
+
+	#[test]
+	fn difference_multiple_test() {
+		assert_eq!(
+			difference_str(
+				&["192.168.10.0/24", "192.168.11.0/24", "172.16.0.0/12"],
+				&["172.16.0.0/12", "192.168.11.0/25"]
+			),
+			["192.168.10.0/24", "192.168.11.128/25"]
+		);
+
+		assert_eq!(
+			difference_str(&["192.168.10.0/24"], &["10.0.0.0/8"]),
+			["192.168.10.0/24"]
+		);
+		assert!(difference_str(&["192.168.10.0/24"], &["0.0.0.0/0"]).is_empty());
+	}
+
+	#[test]
+	fn aggregate_mixed_family_test() {
+		let fixture: Vec<_> = [
+			"2001:db8:1::/48",
+			"10.0.1.0/24",
+			"2001:db8::/48",
+			"10.0.0.0/24",
+			"0.0.0.0/0",
+			"2001:db8::1",
+		]
+		.iter()
+		.map(|s| IpCidr::try_from(*s).unwrap())
+		.collect();
+
+		let actual: Vec<_> = aggregate(fixture)
+			.iter()
+			.map(|c| format!("{}", c))
+			.collect();
+
+		assert_eq!(actual, ["0.0.0.0/0", "2001:db8::/47"]);
+	}
+
+	#[test]
+	fn difference_mixed_family_test() {
+		let ip_cidrs = |values: &[&str]| -> Vec<IpCidr> {
+			values
+				.iter()
+				.map(|s| IpCidr::try_from(*s).unwrap())
+				.collect()
+		};
+
+		let actual: Vec<_> = difference(
+			ip_cidrs(&["10.0.0.0/23", "2001:db8::/32", "192.168.0.0/24"]),
+			ip_cidrs(&["10.0.1.0/24", "2001:db8:8000::/33", "::/0"]),
+		)
+		.iter()
+		.map(|c| format!("{}", c))
+		.collect();
+		assert_eq!(actual, ["10.0.0.0/24", "192.168.0.0/24"]);
+
+		let actual: Vec<_> = difference(
+			ip_cidrs(&["10.0.0.0/8", "2001:db8::/47"]),
+			ip_cidrs(&["0.0.0.0/0", "2001:db8::/48"]),
+		)
+		.iter()
+		.map(|c| format!("{}", c))
+		.collect();
+		assert_eq!(actual, ["2001:db8:1::/48"]);
+	}
+}
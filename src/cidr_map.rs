@@ -0,0 +1,524 @@
+use std::marker::PhantomData;
+
+use crate::cidr::Cidr;
+use crate::prefix::FamilyPrefix;
+
+struct Node<V> {
+	key: u128,
+	prefix: u8,
+	value: Option<V>,
+	children: [Option<Box<Node<V>>>; 2],
+}
+
+impl<V> Node<V> {
+	fn new(key: u128, prefix: u8, value: Option<V>) -> Node<V> {
+		Node {
+			key,
+			prefix,
+			value,
+			children: [None, None],
+		}
+	}
+
+	fn matches(&self, key: u128, prefix: u8) -> bool {
+		self.prefix <= prefix && key & mask(self.prefix) == self.key
+	}
+
+	fn cidr<P: FamilyPrefix>(&self) -> P {
+		P::from_bits(self.key, self.prefix)
+	}
+}
+
+/// Maps CIDR blocks to values with longest-prefix-match lookup, backed by a
+/// path-compressed binary trie. Works for `Cidr` (the default) and `Cidr6`;
+/// see `IpCidrMap` for a dual-stack table.
+///
+/// ```
+/// use cidr_checker::{Cidr, CidrMap, IPv4};
+///
+/// let mut routes = CidrMap::new();
+/// routes.insert(Cidr::try_from("10.0.0.0/8")?, "core");
+/// routes.insert(Cidr::try_from("10.1.0.0/16")?, "branch");
+///
+/// let (cidr, name) = routes.longest_match(&IPv4::try_from("10.1.2.3")?).unwrap();
+/// assert_eq!((cidr.to_string(), *name), ("10.1.0.0/16".to_string(), "branch"));
+/// # Ok::<(), cidr_checker::Error>(())
+/// ```
+pub struct CidrMap<V, P = Cidr> {
+	root: Option<Box<Node<V>>>,
+	len: usize,
+	family: PhantomData<P>,
+}
+
+impl<V, P: FamilyPrefix> CidrMap<V, P> {
+	pub fn new() -> CidrMap<V, P> {
+		CidrMap {
+			root: None,
+			len: 0,
+			family: PhantomData,
+		}
+	}
+
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns the previous value when `cidr` was already present.
+	pub fn insert(&mut self, cidr: P, value: V) -> Option<V> {
+		let previous = insert(&mut self.root, key(&cidr), cidr.prefix_len(), value);
+		if previous.is_none() {
+			self.len += 1;
+		}
+		previous
+	}
+
+	pub fn remove(&mut self, cidr: &P) -> Option<V> {
+		let removed = remove(&mut self.root, key(cidr), cidr.prefix_len());
+		if removed.is_some() {
+			self.len -= 1;
+		}
+		removed
+	}
+
+	/// Exact-match lookup.
+	pub fn get(&self, cidr: &P) -> Option<&V> {
+		let (key, prefix) = (key(cidr), cidr.prefix_len());
+		let mut current = self.root.as_deref();
+
+		while let Some(node) = current {
+			if !node.matches(key, prefix) {
+				return None;
+			}
+
+			if node.prefix == prefix {
+				return node.value.as_ref();
+			}
+
+			current = node.children[bit(key, node.prefix)].as_deref();
+		}
+
+		None
+	}
+
+	pub fn contains_key(&self, cidr: &P) -> bool {
+		self.get(cidr).is_some()
+	}
+
+	/// The most specific entry containing `address`.
+	pub fn longest_match(&self, address: &P::Address) -> Option<(P, &V)> {
+		self.matches(address).last()
+	}
+
+	/// Every entry containing `address`, from the least to the most specific.
+	pub fn all_matches(&self, address: &P::Address) -> Vec<(P, &V)> {
+		self.matches(address).collect()
+	}
+
+	/// Iterates entries in prefix order: by network address, then by prefix length.
+	pub fn iter(&self) -> Iter<'_, V, P> {
+		Iter {
+			stack: self.root.as_deref().into_iter().collect(),
+			family: PhantomData,
+		}
+	}
+
+	fn matches(&self, address: &P::Address) -> impl Iterator<Item = (P, &V)> {
+		let key = P::to_bits(address);
+		let mut current = self.root.as_deref();
+
+		std::iter::from_fn(move || {
+			while let Some(node) = current {
+				if !node.matches(key, P::BITS) {
+					current = None;
+					return None;
+				}
+
+				current = match node.prefix {
+					prefix if prefix == P::BITS => None,
+					prefix => node.children[bit(key, prefix)].as_deref(),
+				};
+
+				if let Some(value) = &node.value {
+					return Some((node.cidr(), value));
+				}
+			}
+
+			None
+		})
+	}
+}
+
+impl<V, P: FamilyPrefix> Default for CidrMap<V, P> {
+	fn default() -> Self {
+		CidrMap::new()
+	}
+}
+
+impl<V, P: FamilyPrefix> FromIterator<(P, V)> for CidrMap<V, P> {
+	fn from_iter<T: IntoIterator<Item = (P, V)>>(iter: T) -> Self {
+		let mut map = CidrMap::new();
+		for (cidr, value) in iter {
+			map.insert(cidr, value);
+		}
+		map
+	}
+}
+
+impl<'a, V, P: FamilyPrefix> IntoIterator for &'a CidrMap<V, P> {
+	type Item = (P, &'a V);
+	type IntoIter = Iter<'a, V, P>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+pub struct Iter<'a, V, P = Cidr> {
+	stack: Vec<&'a Node<V>>,
+	family: PhantomData<P>,
+}
+
+impl<'a, V, P: FamilyPrefix> Iterator for Iter<'a, V, P> {
+	type Item = (P, &'a V);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some(node) = self.stack.pop() {
+			for child in node.children.iter().rev().flatten() {
+				self.stack.push(child);
+			}
+
+			if let Some(value) = &node.value {
+				return Some((node.cidr(), value));
+			}
+		}
+
+		None
+	}
+}
+
+fn key<P: FamilyPrefix>(cidr: &P) -> u128 {
+	P::to_bits(&cidr.network())
+}
+
+fn mask(prefix: u8) -> u128 {
+	match prefix {
+		0 => 0,
+		prefix => u128::MAX << (128 - prefix as u32),
+	}
+}
+
+fn bit(key: u128, index: u8) -> usize {
+	((key >> (127 - index)) & 1) as usize
+}
+
+fn insert<V>(slot: &mut Option<Box<Node<V>>>, key: u128, prefix: u8, value: V) -> Option<V> {
+	let Some(node) = slot else {
+		*slot = Some(Box::new(Node::new(key, prefix, Some(value))));
+		return None;
+	};
+
+	let common = (node.key ^ key)
+		.leading_zeros()
+		.min(node.prefix as u32)
+		.min(prefix as u32) as u8;
+
+	if common == node.prefix {
+		if prefix == node.prefix {
+			return node.value.replace(value);
+		}
+
+		return insert(
+			&mut node.children[bit(key, node.prefix)],
+			key,
+			prefix,
+			value,
+		);
+	}
+
+	let existing = slot.take().unwrap();
+	let mut branch = Node::new(key & mask(common), common, None);
+	let existing_bit = bit(existing.key, common);
+	branch.children[existing_bit] = Some(existing);
+
+	if prefix == common {
+		branch.value = Some(value);
+	} else {
+		branch.children[1 - existing_bit] = Some(Box::new(Node::new(key, prefix, Some(value))));
+	}
+
+	*slot = Some(Box::new(branch));
+	None
+}
+
+fn remove<V>(slot: &mut Option<Box<Node<V>>>, key: u128, prefix: u8) -> Option<V> {
+	let node = slot.as_mut()?;
+
+	if !node.matches(key, prefix) {
+		return None;
+	}
+
+	let removed = if node.prefix == prefix {
+		node.value.take()
+	} else {
+		remove(&mut node.children[bit(key, node.prefix)], key, prefix)
+	};
+
+	if removed.is_some() && node.value.is_none() {
+		match node.children.iter_mut().flatten().count() {
+			0 => *slot = None,
+			1 => {
+				let child = node.children.iter_mut().find_map(|c| c.take());
+				*slot = child;
+			}
+			_ => {}
+		}
+	}
+
+	removed
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::cidr::Cidr;
+	use crate::cidr6::Cidr6;
+	use crate::cidr_map::CidrMap;
+	use crate::ipv4::IPv4;
+	use crate::ipv4_mask::IPv4Mask;
+	use crate::ipv6::IPv6;
+
+	fn cidr(value: &str) -> Cidr {
+		Cidr::try_from(value).unwrap()
+	}
+
+	fn addr(value: &str) -> IPv4 {
+		IPv4::try_from(value).unwrap()
+	}
+
+	fn fixture() -> CidrMap<&'static str> {
+		[
+			(cidr("0.0.0.0/0"), "default"),
+			(cidr("10.0.0.0/8"), "corp"),
+			(cidr("10.20.0.0/16"), "lab"),
+			(cidr("10.20.30.0/24"), "rack"),
+			(cidr("192.168.0.0/16"), "home"),
+		]
+		.into_iter()
+		.collect()
+	}
+
+	#[test]
+	fn insert_and_get_test() {
+		let mut fixture = fixture();
+		assert_eq!(fixture.len(), 5);
+
+		assert_eq!(fixture.get(&cidr("10.20.0.0/16")), Some(&"lab"));
+		assert_eq!(fixture.get(&cidr("0.0.0.0/0")), Some(&"default"));
+		assert_eq!(fixture.get(&cidr("10.20.0.0/15")), None);
+		assert_eq!(fixture.get(&cidr("10.21.0.0/16")), None);
+		assert!(fixture.contains_key(&cidr("192.168.0.0/16")));
+
+		assert_eq!(fixture.insert(cidr("10.20.0.0/16"), "lab2"), Some("lab"));
+		assert_eq!(fixture.get(&cidr("10.20.0.0/16")), Some(&"lab2"));
+		assert_eq!(fixture.len(), 5);
+	}
+
+	#[test]
+	fn insert_branch_test() {
+		let mut fixture = CidrMap::new();
+		assert!(fixture.is_empty());
+
+		fixture.insert(cidr("10.0.1.0/24"), 1);
+		fixture.insert(cidr("10.0.2.0/24"), 2);
+		assert_eq!(fixture.get(&cidr("10.0.0.0/22")), None);
+
+		fixture.insert(cidr("10.0.0.0/22"), 3);
+		assert_eq!(fixture.get(&cidr("10.0.0.0/22")), Some(&3));
+		assert_eq!(fixture.get(&cidr("10.0.1.0/24")), Some(&1));
+		assert_eq!(fixture.get(&cidr("10.0.2.0/24")), Some(&2));
+		assert_eq!(fixture.len(), 3);
+	}
+
+	#[test]
+	fn remove_test() {
+		let mut fixture = fixture();
+
+		assert_eq!(fixture.remove(&cidr("10.20.0.0/16")), Some("lab"));
+		assert_eq!(fixture.remove(&cidr("10.20.0.0/16")), None);
+		assert_eq!(fixture.remove(&cidr("172.16.0.0/12")), None);
+		assert_eq!(fixture.len(), 4);
+
+		assert_eq!(fixture.get(&cidr("10.20.30.0/24")), Some(&"rack"));
+		assert_eq!(
+			fixture.longest_match(&addr("10.20.1.1")),
+			Some((cidr("10.0.0.0/8"), &"corp"))
+		);
+
+		for cidr in [
+			cidr("0.0.0.0/0"),
+			cidr("10.0.0.0/8"),
+			cidr("10.20.30.0/24"),
+			cidr("192.168.0.0/16"),
+		] {
+			assert!(fixture.remove(&cidr).is_some());
+		}
+
+		assert!(fixture.is_empty());
+		assert!(fixture.root.is_none());
+	}
+
+	#[test]
+	fn longest_match_test() {
+		let fixture = fixture();
+
+		assert_eq!(
+			fixture.longest_match(&addr("10.20.30.40")),
+			Some((cidr("10.20.30.0/24"), &"rack"))
+		);
+		assert_eq!(
+			fixture.longest_match(&addr("10.20.31.40")),
+			Some((cidr("10.20.0.0/16"), &"lab"))
+		);
+		assert_eq!(
+			fixture.longest_match(&addr("10.1.1.1")),
+			Some((cidr("10.0.0.0/8"), &"corp"))
+		);
+		assert_eq!(
+			fixture.longest_match(&addr("8.8.8.8")),
+			Some((cidr("0.0.0.0/0"), &"default"))
+		);
+
+		let fixture: CidrMap<_> = [(cidr("10.0.0.1/32"), 1)].into_iter().collect();
+		assert_eq!(
+			fixture.longest_match(&addr("10.0.0.1")),
+			Some((cidr("10.0.0.1/32"), &1))
+		);
+		assert_eq!(fixture.longest_match(&addr("10.0.0.2")), None);
+	}
+
+	#[test]
+	fn all_matches_test() {
+		let fixture = fixture();
+
+		let actual: Vec<_> = fixture
+			.all_matches(&addr("10.20.30.40"))
+			.into_iter()
+			.map(|(_, v)| *v)
+			.collect();
+		assert_eq!(actual, ["default", "corp", "lab", "rack"]);
+
+		let actual = fixture.all_matches(&addr("172.16.0.1"));
+		assert_eq!(actual, [(cidr("0.0.0.0/0"), &"default")]);
+	}
+
+	#[test]
+	fn iter_test() {
+		let fixture = fixture();
+
+		let actual: Vec<_> = fixture.iter().map(|(c, _)| c).collect();
+		let mut expected = actual.clone();
+		expected.sort();
+
+		assert_eq!(actual, expected);
+		assert_eq!(
+			actual,
+			[
+				cidr("0.0.0.0/0"),
+				cidr("10.0.0.0/8"),
+				cidr("10.20.0.0/16"),
+				cidr("10.20.30.0/24"),
+				cidr("192.168.0.0/16"),
+			]
+		);
+	}
+
+	#[test]
+	fn naive_comparison_test() {
+		let mut seed = 0x2545_f491u32;
+		let mut next = move || {
+			seed ^= seed << 13;
+			seed ^= seed >> 17;
+			seed ^= seed << 5;
+			seed
+		};
+
+		let mut entries = Vec::new();
+		let mut fixture = CidrMap::new();
+		for i in 0..2000 {
+			let prefix = (next() % 33) as u8;
+			let entry = Cidr::from_host(
+				IPv4::from(next() & 0xf0f0_ffff),
+				IPv4Mask::new(prefix).unwrap(),
+			);
+
+			if fixture.insert(entry, i).is_none() {
+				entries.push((entry, i));
+			} else {
+				entries.iter_mut().find(|(c, _)| *c == entry).unwrap().1 = i;
+			}
+		}
+
+		for (cidr, _) in entries.iter().step_by(3) {
+			fixture.remove(cidr);
+		}
+		let entries: Vec<_> = entries
+			.iter()
+			.enumerate()
+			.filter(|(i, _)| i % 3 != 0)
+			.map(|(_, e)| *e)
+			.collect();
+
+		assert_eq!(fixture.len(), entries.len());
+
+		for _ in 0..2000 {
+			let address = IPv4::from(next() & 0xf0f0_ffff);
+			let expected = entries
+				.iter()
+				.filter(|(c, _)| c.contains(&address))
+				.max_by_key(|(c, _)| c.subnet_mask().cidr())
+				.map(|(c, v)| (*c, v));
+
+			assert_eq!(fixture.longest_match(&address), expected);
+		}
+	}
+
+	#[test]
+	fn ipv6_test() {
+		let cidr6 = |value: &str| Cidr6::try_from(value).unwrap();
+		let addr6 = |value: &str| IPv6::try_from(value).unwrap();
+
+		let mut fixture: CidrMap<_, Cidr6> = [
+			(cidr6("::/0"), "default"),
+			(cidr6("2001:db8::/32"), "doc"),
+			(cidr6("2001:db8:1::/48"), "site"),
+			(cidr6("2001:db8:1:2::1"), "host"),
+		]
+		.into_iter()
+		.collect();
+
+		assert_eq!(
+			fixture.longest_match(&addr6("2001:db8:1:2::1")),
+			Some((cidr6("2001:db8:1:2::1/128"), &"host"))
+		);
+		assert_eq!(
+			fixture.longest_match(&addr6("2001:db8:1:2::2")),
+			Some((cidr6("2001:db8:1::/48"), &"site"))
+		);
+		assert_eq!(
+			fixture.longest_match(&addr6("2001:db9::")),
+			Some((cidr6("::/0"), &"default"))
+		);
+
+		assert_eq!(fixture.remove(&cidr6("::/0")), Some("default"));
+		assert_eq!(fixture.longest_match(&addr6("fe80::1")), None);
+
+		let actual: Vec<_> = fixture.iter().map(|(c, _)| format!("{}", c)).collect();
+		assert_eq!(
+			actual,
+			["2001:db8::/32", "2001:db8:1::/48", "2001:db8:1:2::1/128"]
+		);
+	}
+}
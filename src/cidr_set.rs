@@ -0,0 +1,574 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::cidr::Cidr;
+use crate::cidr6::Cidr6;
+use crate::ipv4::IPv4;
+use crate::ipv4_network_range::IPv4NetworkRange;
+use crate::ipv6::IPv6;
+use crate::ipv6_network_range::IPv6NetworkRange;
+use crate::prefix::FamilyPrefix;
+
+/// A set of addresses of one family kept as sorted, non-overlapping and
+/// non-adjacent ranges. Works for `Cidr` (the default) and `Cidr6`; see
+/// `IpCidrSet` for a dual-stack set.
+///
+/// ```
+/// use cidr_checker::{Cidr, CidrSet};
+///
+/// let mut set = CidrSet::new();
+/// set.insert(Cidr::try_from("10.0.0.0/25")?);
+/// set.insert(Cidr::try_from("10.0.0.128/25")?);
+/// set.remove(Cidr::try_from("10.0.0.64/26")?);
+///
+/// let cidrs: Vec<_> = set.iter().map(|c| c.to_string()).collect();
+/// assert_eq!(cidrs, ["10.0.0.0/26", "10.0.0.128/25"]);
+/// # Ok::<(), cidr_checker::Error>(())
+/// ```
+#[derive(Eq, PartialEq, Hash, Clone)]
+pub struct CidrSet<P = Cidr> {
+	/// Inclusive bounds as right-aligned address values.
+	ranges: Vec<(u128, u128)>,
+	family: PhantomData<P>,
+}
+
+impl<P: FamilyPrefix> CidrSet<P> {
+	/// The highest address of the family, right-aligned.
+	const MAX: u128 = u128::MAX >> (128 - P::BITS as u32);
+
+	pub fn new() -> CidrSet<P> {
+		CidrSet::from_merged(Vec::new())
+	}
+
+	pub fn insert(&mut self, cidr: P) {
+		let (begin, end) = Self::bounds_of(&cidr);
+		self.insert_bounds(begin, end)
+	}
+
+	pub fn remove(&mut self, cidr: P) {
+		let (begin, end) = Self::bounds_of(&cidr);
+		self.remove_bounds(begin, end)
+	}
+
+	pub fn contains(&self, address: &P::Address) -> bool {
+		let value = Self::value(address);
+		let index = self.ranges.partition_point(|&(_, end)| end < value);
+		self.ranges
+			.get(index)
+			.is_some_and(|&(begin, _)| begin <= value)
+	}
+
+	pub fn union(&self, other: &CidrSet<P>) -> CidrSet<P> {
+		let bounds = self.ranges.iter().chain(&other.ranges).copied().collect();
+		CidrSet::from_bounds(bounds)
+	}
+
+	pub fn intersection(&self, other: &CidrSet<P>) -> CidrSet<P> {
+		let mut result = Vec::new();
+		let mut lhs = self.ranges.iter().peekable();
+		let mut rhs = other.ranges.iter().peekable();
+
+		while let (Some(&&(lhs_begin, lhs_end)), Some(&&(rhs_begin, rhs_end))) =
+			(lhs.peek(), rhs.peek())
+		{
+			let begin = lhs_begin.max(rhs_begin);
+			let end = lhs_end.min(rhs_end);
+
+			if begin <= end {
+				result.push((begin, end));
+			}
+
+			if lhs_end < rhs_end {
+				lhs.next();
+			} else {
+				rhs.next();
+			}
+		}
+
+		CidrSet::from_merged(result)
+	}
+
+	pub fn difference(&self, other: &CidrSet<P>) -> CidrSet<P> {
+		self.intersection(&other.complement())
+	}
+
+	pub fn complement(&self) -> CidrSet<P> {
+		let mut result = Vec::new();
+		let mut next = Some(0u128);
+
+		for &(begin, end) in &self.ranges {
+			if let Some(next) = next.filter(|next| *next < begin) {
+				result.push((next, begin - 1));
+			}
+			next = end.checked_add(1).filter(|next| *next <= Self::MAX);
+		}
+
+		if let Some(next) = next {
+			result.push((next, Self::MAX));
+		}
+
+		CidrSet::from_merged(result)
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.ranges.is_empty()
+	}
+
+	/// Iterates the set as the minimal list of CIDR blocks, in ascending order.
+	pub fn iter(&self) -> impl Iterator<Item = P> + '_ {
+		self.ranges
+			.iter()
+			.flat_map(|&(begin, end)| Self::prefixes(begin, end))
+	}
+
+	/// Merges `begin..=end` into the ranges it overlaps or touches and
+	/// replaces just that window, so each insert is a binary search plus one
+	/// splice rather than a rebuild.
+	fn insert_bounds(&mut self, mut begin: u128, mut end: u128) {
+		let start = self
+			.ranges
+			.partition_point(|&(_, last)| last.saturating_add(1) < begin);
+		let stop = self
+			.ranges
+			.partition_point(|&(first, _)| first <= end.saturating_add(1));
+
+		if start < stop {
+			begin = begin.min(self.ranges[start].0);
+			end = end.max(self.ranges[stop - 1].1);
+		}
+
+		self.ranges.splice(start..stop, [(begin, end)]);
+	}
+
+	/// Cuts `begin..=end` out of the ranges it overlaps, keeping the parts of
+	/// the first and last of them that stick out on either side.
+	fn remove_bounds(&mut self, begin: u128, end: u128) {
+		let start = self.ranges.partition_point(|&(_, last)| last < begin);
+		let stop = self.ranges.partition_point(|&(first, _)| first <= end);
+
+		if start == stop {
+			return;
+		}
+
+		let (first, _) = self.ranges[start];
+		let (_, last) = self.ranges[stop - 1];
+
+		let mut pieces = Vec::with_capacity(2);
+		if first < begin {
+			pieces.push((first, begin - 1));
+		}
+		if end < last {
+			pieces.push((end + 1, last));
+		}
+
+		self.ranges.splice(start..stop, pieces);
+	}
+
+	fn value(address: &P::Address) -> u128 {
+		P::to_bits(address) >> (128 - P::BITS as u32)
+	}
+
+	fn bounds_of(cidr: &P) -> (u128, u128) {
+		(Self::value(&cidr.network()), Self::value(&cidr.last()))
+	}
+
+	/// The largest aligned blocks covering `begin..=end`, in order.
+	fn prefixes(mut begin: u128, end: u128) -> Vec<P> {
+		let last = |begin: u128, host: u32| match host {
+			128 => u128::MAX,
+			_ => begin | ((1u128 << host) - 1),
+		};
+
+		let mut result = Vec::new();
+
+		loop {
+			let mut host = begin.trailing_zeros().min(P::BITS as u32);
+			while last(begin, host) > end {
+				host -= 1;
+			}
+
+			let bits = begin << (128 - P::BITS as u32);
+			result.push(P::from_bits(bits, P::BITS - host as u8));
+
+			if last(begin, host) >= end {
+				return result;
+			}
+			begin = last(begin, host) + 1;
+		}
+	}
+
+	fn from_bounds(mut bounds: Vec<(u128, u128)>) -> CidrSet<P> {
+		bounds.sort();
+
+		let mut merged: Vec<(u128, u128)> = Vec::with_capacity(bounds.len());
+		for (begin, end) in bounds {
+			match merged.last_mut() {
+				Some((_, last_end)) if begin <= last_end.saturating_add(1) => {
+					*last_end = (*last_end).max(end)
+				}
+				_ => merged.push((begin, end)),
+			}
+		}
+
+		CidrSet::from_merged(merged)
+	}
+
+	/// `ranges` must already be sorted, non-overlapping and non-adjacent.
+	fn from_merged(ranges: Vec<(u128, u128)>) -> CidrSet<P> {
+		CidrSet {
+			ranges,
+			family: PhantomData,
+		}
+	}
+}
+
+impl<P: FamilyPrefix + Display> CidrSet<P> {
+	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{{")?;
+		for (i, cidr) in self.iter().enumerate() {
+			if i > 0 {
+				write!(f, ", ")?;
+			}
+			write!(f, "{}", cidr)?;
+		}
+		write!(f, "}}")
+	}
+}
+
+impl CidrSet<Cidr> {
+	pub fn insert_range(&mut self, range: IPv4NetworkRange) {
+		self.insert_bounds(range.begin().to_u32() as u128, range.end().to_u32() as u128)
+	}
+
+	pub fn remove_range(&mut self, range: IPv4NetworkRange) {
+		self.remove_bounds(range.begin().to_u32() as u128, range.end().to_u32() as u128)
+	}
+
+	/// The number of addresses in the set.
+	pub fn len(&self) -> u64 {
+		self.ranges
+			.iter()
+			.map(|&(begin, end)| (end - begin) as u64 + 1)
+			.sum()
+	}
+
+	pub fn ranges(&self) -> Vec<IPv4NetworkRange> {
+		self.ranges
+			.iter()
+			.map(|&(begin, end)| {
+				IPv4NetworkRange::new(IPv4::from(begin as u32), IPv4::from(end as u32)).unwrap()
+			})
+			.collect()
+	}
+}
+
+impl CidrSet<Cidr6> {
+	pub fn insert_range(&mut self, range: IPv6NetworkRange) {
+		self.insert_bounds(range.begin().to_u128(), range.end().to_u128())
+	}
+
+	pub fn remove_range(&mut self, range: IPv6NetworkRange) {
+		self.remove_bounds(range.begin().to_u128(), range.end().to_u128())
+	}
+
+	/// The number of addresses in the set, or `None` when it does not fit in
+	/// a `u128`, which only happens for all of `::/0`.
+	pub fn len(&self) -> Option<u128> {
+		self.ranges.iter().try_fold(0u128, |sum, &(begin, end)| {
+			(end - begin).checked_add(1)?.checked_add(sum)
+		})
+	}
+
+	pub fn ranges(&self) -> Vec<IPv6NetworkRange> {
+		self.ranges
+			.iter()
+			.map(|&(begin, end)| IPv6NetworkRange::new(IPv6::from(begin), IPv6::from(end)).unwrap())
+			.collect()
+	}
+}
+
+impl<P: FamilyPrefix> Default for CidrSet<P> {
+	fn default() -> Self {
+		CidrSet::new()
+	}
+}
+
+impl<P: FamilyPrefix> FromIterator<P> for CidrSet<P> {
+	fn from_iter<T: IntoIterator<Item = P>>(iter: T) -> Self {
+		let bounds = iter
+			.into_iter()
+			.map(|cidr| Self::bounds_of(&cidr))
+			.collect();
+		CidrSet::from_bounds(bounds)
+	}
+}
+
+impl<P: FamilyPrefix> Extend<P> for CidrSet<P> {
+	fn extend<T: IntoIterator<Item = P>>(&mut self, iter: T) {
+		*self = self.union(&iter.into_iter().collect());
+	}
+}
+
+impl<P: FamilyPrefix + Display> Debug for CidrSet<P> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+impl<P: FamilyPrefix + Display> Display for CidrSet<P> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::cidr::Cidr;
+	use crate::cidr6::Cidr6;
+	use crate::cidr_set::CidrSet;
+	use crate::ipv4::IPv4;
+	use crate::ipv4_mask::IPv4Mask;
+	use crate::ipv4_network_range::IPv4NetworkRange;
+	use crate::ipv6::IPv6;
+
+	fn set(values: &[&str]) -> CidrSet {
+		values.iter().map(|s| Cidr::try_from(*s).unwrap()).collect()
+	}
+
+	fn addr(value: &str) -> IPv4 {
+		IPv4::try_from(value).unwrap()
+	}
+
+	#[test]
+	fn from_iter_test() {
+		let fixture = set(&[
+			"10.0.0.0/25",
+			"10.0.0.128/25",
+			"10.0.0.64/26",
+			"192.168.0.0/16",
+		]);
+
+		assert_eq!(fixture.ranges().len(), 2);
+		assert_eq!(format!("{}", fixture), "{10.0.0.0/24, 192.168.0.0/16}");
+	}
+
+	#[test]
+	fn insert_test() {
+		let mut fixture = CidrSet::new();
+		assert!(fixture.is_empty());
+
+		fixture.insert(Cidr::try_from("10.0.0.0/24").unwrap());
+		fixture.insert(Cidr::try_from("10.0.2.0/24").unwrap());
+		assert_eq!(fixture.ranges().len(), 2);
+
+		fixture.insert(Cidr::try_from("10.0.1.0/24").unwrap());
+		assert_eq!(fixture.ranges().len(), 1);
+		assert_eq!(format!("{}", fixture), "{10.0.0.0/23, 10.0.2.0/24}");
+	}
+
+	#[test]
+	fn remove_test() {
+		let mut fixture = set(&["10.0.0.0/8"]);
+		fixture.remove(Cidr::try_from("10.20.0.0/16").unwrap());
+		fixture.remove(Cidr::try_from("192.168.0.0/16").unwrap());
+
+		assert!(fixture.contains(&addr("10.19.255.255")));
+		assert!(!fixture.contains(&addr("10.20.0.0")));
+		assert!(fixture.contains(&addr("10.21.0.0")));
+		assert_eq!(fixture.len(), (1 << 24) - (1 << 16));
+	}
+
+	#[test]
+	fn contains_test() {
+		let fixture = set(&["10.0.0.0/24", "192.168.0.0/16"]);
+
+		assert!(fixture.contains(&addr("10.0.0.0")));
+		assert!(fixture.contains(&addr("10.0.0.255")));
+		assert!(!fixture.contains(&addr("10.0.1.0")));
+		assert!(fixture.contains(&addr("192.168.100.1")));
+		assert!(!fixture.contains(&addr("9.255.255.255")));
+		assert!(!fixture.contains(&addr("255.255.255.255")));
+		assert!(!CidrSet::<Cidr>::new().contains(&addr("10.0.0.0")));
+	}
+
+	#[test]
+	fn union_test() {
+		let actual = set(&["10.0.0.0/24"]).union(&set(&["10.0.1.0/24", "172.16.0.0/12"]));
+		assert_eq!(format!("{}", actual), "{10.0.0.0/23, 172.16.0.0/12}");
+	}
+
+	#[test]
+	fn intersection_test() {
+		let lhs = set(&["10.0.0.0/8", "192.168.0.0/24"]);
+		let rhs = set(&[
+			"10.1.0.0/16",
+			"10.200.0.0/16",
+			"192.168.0.128/25",
+			"172.16.0.0/12",
+		]);
+
+		let actual = lhs.intersection(&rhs);
+		assert_eq!(
+			format!("{}", actual),
+			"{10.1.0.0/16, 10.200.0.0/16, 192.168.0.128/25}"
+		);
+		assert!(lhs.intersection(&CidrSet::new()).is_empty());
+	}
+
+	#[test]
+	fn difference_test() {
+		let actual = set(&["10.0.0.0/23"]).difference(&set(&["10.0.0.128/25", "10.0.1.0/24"]));
+		assert_eq!(format!("{}", actual), "{10.0.0.0/25}");
+	}
+
+	#[test]
+	fn complement_test() {
+		let actual = CidrSet::<Cidr>::new().complement();
+		assert_eq!(format!("{}", actual), "{0.0.0.0/0}");
+		assert_eq!(actual.len(), 1 << 32);
+		assert!(actual.complement().is_empty());
+
+		let actual = set(&["0.0.0.0/1"]).complement();
+		assert_eq!(format!("{}", actual), "{128.0.0.0/1}");
+
+		let actual = set(&["10.0.0.0/8"]).complement();
+		assert_eq!(actual.len(), (1 << 32) - (1 << 24));
+		assert!(!actual.contains(&addr("10.1.2.3")));
+		assert!(actual.contains(&addr("0.0.0.0")));
+		assert!(actual.contains(&addr("255.255.255.255")));
+	}
+
+	#[test]
+	fn iter_test() {
+		let actual: Vec<_> = set(&["10.0.0.5/32", "10.0.0.6/31", "10.0.0.8/29"])
+			.iter()
+			.map(|c| format!("{}", c))
+			.collect();
+
+		assert_eq!(actual, ["10.0.0.5/32", "10.0.0.6/31", "10.0.0.8/29"]);
+	}
+
+	#[test]
+	fn extend_test() {
+		let mut fixture = set(&["10.0.0.0/25"]);
+		fixture.extend([Cidr::try_from("10.0.0.128/25").unwrap()]);
+
+		assert_eq!(format!("{:?}", fixture), "{10.0.0.0/24}");
+	}
+
+	#[test]
+	fn insert_remove_window_test() {
+		let mut fixture = set(&["10.0.0.0/24", "10.0.2.0/24", "10.0.4.0/24", "10.0.8.0/24"]);
+
+		fixture.insert(Cidr::try_from("10.0.1.0/24").unwrap());
+		fixture.insert(Cidr::try_from("10.0.3.0/24").unwrap());
+		assert_eq!(
+			format!("{}", fixture),
+			"{10.0.0.0/22, 10.0.4.0/24, 10.0.8.0/24}"
+		);
+
+		fixture.remove(Cidr::try_from("10.0.2.0/23").unwrap());
+		fixture.remove(Cidr::try_from("10.0.6.0/24").unwrap());
+		assert_eq!(
+			format!("{}", fixture),
+			"{10.0.0.0/23, 10.0.4.0/24, 10.0.8.0/24}"
+		);
+
+		fixture.insert_range(IPv4NetworkRange::new(addr("10.0.1.128"), addr("10.0.8.0")).unwrap());
+		assert_eq!(fixture.ranges().len(), 1);
+		assert_eq!(fixture.len(), 9 * 256);
+
+		fixture.remove_range(IPv4NetworkRange::new(addr("10.0.0.1"), addr("10.0.8.254")).unwrap());
+		assert_eq!(format!("{}", fixture), "{10.0.0.0/32, 10.0.8.255/32}");
+
+		let mut fixture = CidrSet::new();
+		fixture.insert(Cidr::try_from("0.0.0.0/0").unwrap());
+		fixture.remove(Cidr::try_from("0.0.0.0/32").unwrap());
+		fixture.remove(Cidr::try_from("255.255.255.255/32").unwrap());
+		assert_eq!(fixture.len(), (1 << 32) - 2);
+	}
+
+	/// Single inserts and removes must agree with the whole-set operations.
+	#[test]
+	fn insert_remove_matches_union_test() {
+		let mut seed = 0x2545_f491_4f6c_dd1du64;
+		let mut fixture = CidrSet::new();
+		let mut expected = CidrSet::new();
+
+		for _ in 0..2000 {
+			seed ^= seed << 13;
+			seed ^= seed >> 7;
+			seed ^= seed << 17;
+
+			let prefix = 20 + (seed % 13) as u8;
+			let address = IPv4::from(0x0a00_0000 | (seed >> 32) as u32 & 0x000f_ffff);
+			let cidr = Cidr::from_host(address, IPv4Mask::new(prefix).unwrap());
+			let single = CidrSet::from_iter([cidr]);
+
+			if seed.is_multiple_of(3) {
+				fixture.remove(cidr);
+				expected = expected.difference(&single);
+			} else {
+				fixture.insert(cidr);
+				expected = expected.union(&single);
+			}
+
+			assert_eq!(fixture, expected);
+		}
+	}
+
+	fn set6(values: &[&str]) -> CidrSet<Cidr6> {
+		values
+			.iter()
+			.map(|s| Cidr6::try_from(*s).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn ipv6_insert_remove_test() {
+		let mut fixture = set6(&["2001:db8::/33", "2001:db8:8000::/33"]);
+		assert_eq!(format!("{}", fixture), "{2001:db8::/32}");
+
+		fixture.remove(Cidr6::try_from("2001:db8:1::/48").unwrap());
+		assert!(fixture.contains(&IPv6::try_from("2001:db8::1").unwrap()));
+		assert!(!fixture.contains(&IPv6::try_from("2001:db8:1::1").unwrap()));
+		assert!(fixture.contains(&IPv6::try_from("2001:db8:2::1").unwrap()));
+		assert_eq!(fixture.ranges().len(), 2);
+
+		fixture.insert(Cidr6::try_from("2001:db8:1::/48").unwrap());
+		assert_eq!(format!("{}", fixture), "{2001:db8::/32}");
+	}
+
+	#[test]
+	fn ipv6_len_test() {
+		assert_eq!(CidrSet::<Cidr6>::new().len(), Some(0));
+		assert_eq!(set6(&["2001:db8::/120", "::1/128"]).len(), Some(257));
+		assert_eq!(set6(&["::/1", "8000::/2"]).len(), Some(3 << 126));
+		assert_eq!(set6(&["::/0"]).len(), None);
+		assert_eq!(set6(&["::/1", "8000::/1"]).len(), None);
+	}
+
+	#[test]
+	fn ipv6_complement_test() {
+		let actual = CidrSet::<Cidr6>::new().complement();
+		assert_eq!(format!("{}", actual), "{::/0}");
+		assert!(actual.complement().is_empty());
+
+		let actual = set6(&["::/1"]).complement();
+		assert_eq!(format!("{}", actual), "{8000::/1}");
+
+		let actual = set6(&["ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128"]).complement();
+		assert_eq!(actual.iter().count(), 128);
+		assert!(!actual.contains(&IPv6::from(u128::MAX)));
+		assert!(actual.contains(&IPv6::from(u128::MAX - 1)));
+	}
+
+	#[test]
+	fn ipv6_difference_test() {
+		let actual = set6(&["2001:db8::/32", "::/0"]).difference(&set6(&["::/1"]));
+		assert_eq!(format!("{}", actual), "{8000::/1}");
+
+		let actual = set6(&["2001:db8::/47"]).difference(&set6(&["2001:db8:1::/48"]));
+		assert_eq!(format!("{:?}", actual), "{2001:db8::/48}");
+	}
+}
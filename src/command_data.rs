@@ -1,6 +1,318 @@
+use cidr_checker::{Cidr, IPv4, IPv4Mask, IPv4ParseMode, IpAddress, IpCidr, IpCidrError};
+
+use crate::command_error::CommandError;
+
 pub struct CommandData {
 	cidr: Option<String>,
 	mask: Option<String>,
 	network: Option<String>,
 	address: Option<String>,
+	mode: IPv4ParseMode,
+}
+
+impl CommandData {
+	pub fn parse<'a, T: Iterator<Item = &'a String>>(scr: &mut T) -> Result<Self, CommandError> {
+		let mut data = CommandData {
+			cidr: None,
+			mask: None,
+			network: None,
+			address: None,
+			mode: IPv4ParseMode::Lenient,
+		};
+
+		while let Some(option) = scr.next() {
+			let slot = match option.as_str() {
+				"--strict" => {
+					data.mode = IPv4ParseMode::Strict;
+					continue;
+				}
+				"-c" => &mut data.cidr,
+				"-s" => &mut data.mask,
+				"-n" => &mut data.network,
+				"-a" => &mut data.address,
+				_ => return Err(CommandError::UnknownOption(option.clone())),
+			};
+
+			if slot.is_some() {
+				return Err(CommandError::DuplicateOption(option.clone()));
+			}
+
+			let value = scr
+				.next()
+				.ok_or_else(|| CommandError::MissingValue(option.clone()))?;
+			*slot = Some(value.clone());
+		}
+
+		data.verify()?;
+		Ok(data)
+	}
+
+	/// `-c` accepts either family; `-n`/`-s` describe an IPv4 network only,
+	/// since subnet mask notation has no IPv6 counterpart. `--strict` applies
+	/// to all three.
+	pub fn target(&self) -> Result<IpCidr, CommandError> {
+		let (network, mask) = match (&self.cidr, &self.network, &self.mask) {
+			(Some(cidr), _, _) => {
+				return IpCidr::parse_with(cidr, self.mode).map_err(CommandError::InvalidCidr)
+			}
+			(None, Some(network), Some(mask)) => (network, mask),
+			_ => return Err(CommandError::MissingCidr),
+		};
+
+		let mask = IPv4Mask::parse_with(mask, self.mode).map_err(CommandError::InvalidMask)?;
+
+		let network = IPv4::parse_with(network, self.mode).map_err(CommandError::InvalidNetwork)?;
+		Cidr::new(network, mask)
+			.map(IpCidr::V4)
+			.map_err(|e| CommandError::InvalidCidr(IpCidrError::V4(e)))
+	}
+
+	pub fn address(&self) -> Result<IpAddress, CommandError> {
+		let address = self.address.as_ref().ok_or(CommandError::MissingAddress)?;
+		IpAddress::parse_with(address, self.mode).map_err(CommandError::InvalidAddress)
+	}
+
+	fn verify(&self) -> Result<(), CommandError> {
+		match (&self.cidr, &self.network, &self.mask) {
+			(Some(_), None, None) => {}
+			(Some(_), _, _) => return Err(CommandError::ConflictingOptions),
+			(None, Some(_), None) => return Err(CommandError::MissingMask),
+			(None, None, Some(_)) => return Err(CommandError::MissingNetwork),
+			(None, None, None) => return Err(CommandError::MissingCidr),
+			(None, Some(_), Some(_)) => {}
+		}
+
+		if self.address.is_none() {
+			Err(CommandError::MissingAddress)
+		} else {
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cidr_checker::{
+		Cidr6Error, CidrError, IPv4, IPv4Mask, IPv4MaskError, IPv6, IPv6Mask, IpAddress,
+		IpAddressError, IpCidrError, Ipv4Error,
+	};
+
+	use crate::command_data::CommandData;
+	use crate::command_error::CommandError;
+
+	fn parse(args: &[&str]) -> Result<CommandData, CommandError> {
+		let args: Vec<_> = args.iter().map(|s| s.to_string()).collect();
+		CommandData::parse(&mut args.iter())
+	}
+
+	/// `CommandError` holds an `io::Error` and so has no `PartialEq`; the
+	/// message carries every field, so comparing it is just as strict.
+	fn assert_error<T>(actual: Result<T, CommandError>, expected: CommandError) {
+		match actual {
+			Ok(_) => unreachable!(),
+			Err(err) => assert_eq!(err.to_string(), expected.to_string()),
+		}
+	}
+
+	#[test]
+	fn parse_cidr_test() {
+		let fixture = parse(&["-c", "192.168.10.0/24", "-a", "192.168.10.15"]).unwrap();
+
+		assert_eq!(format!("{}", fixture.target().unwrap()), "192.168.10.0/24");
+		assert_eq!(
+			fixture.address().unwrap(),
+			IpAddress::try_from("192.168.10.15").unwrap()
+		);
+	}
+
+	#[test]
+	fn parse_network_and_mask_test() {
+		let fixture = parse(&[
+			"-a",
+			"192.168.19.15",
+			"-s",
+			"255.255.255.0",
+			"-n",
+			"192.168.10.0",
+		])
+		.unwrap();
+
+		assert_eq!(format!("{}", fixture.target().unwrap()), "192.168.10.0/24");
+		assert_eq!(
+			fixture.address().unwrap(),
+			IpAddress::try_from("192.168.19.15").unwrap()
+		);
+	}
+
+	#[test]
+	fn parse_error_test() {
+		assert_error(
+			parse(&["-x", "1"]),
+			CommandError::UnknownOption("-x".to_string()),
+		);
+		assert_error(parse(&["-a"]), CommandError::MissingValue("-a".to_string()));
+		assert_error(
+			parse(&["-a", "10.0.0.1", "-a", "10.0.0.2"]),
+			CommandError::DuplicateOption("-a".to_string()),
+		);
+		assert_error(
+			parse(&["-c", "10.0.0.0/8", "-n", "10.0.0.0", "-a", "10.0.0.1"]),
+			CommandError::ConflictingOptions,
+		);
+		assert_error(parse(&["-a", "10.0.0.1"]), CommandError::MissingCidr);
+		assert_error(
+			parse(&["-n", "10.0.0.0", "-a", "10.0.0.1"]),
+			CommandError::MissingMask,
+		);
+		assert_error(
+			parse(&["-s", "255.0.0.0", "-a", "10.0.0.1"]),
+			CommandError::MissingNetwork,
+		);
+		assert_error(parse(&["-c", "10.0.0.0/8"]), CommandError::MissingAddress);
+	}
+
+	#[test]
+	fn parse_mask_notation_test() {
+		for mask in ["255.255.255.0", "/24", "24", "0xffffff00"] {
+			let fixture = parse(&["-s", mask, "-n", "192.168.10.0", "-a", "192.168.10.1"]).unwrap();
+			assert_eq!(format!("{}", fixture.target().unwrap()), "192.168.10.0/24");
+		}
+	}
+
+	#[test]
+	fn parse_strict_test() {
+		let fixture = parse(&["-c", "10.0.0.0/08", "-a", "010.0.0.1"]).unwrap();
+		assert_eq!(format!("{}", fixture.target().unwrap()), "10.0.0.0/8");
+		assert_eq!(
+			fixture.address().unwrap(),
+			IpAddress::try_from("10.0.0.1").unwrap()
+		);
+
+		let fixture = parse(&["--strict", "-c", "10.0.0.0/08", "-a", "010.0.0.1"]).unwrap();
+		assert_error(
+			fixture.target(),
+			CommandError::InvalidCidr(IpCidrError::V4(CidrError::PrefixError(
+				IPv4MaskError::FormatError("08".to_string()),
+			))),
+		);
+		assert_error(
+			fixture.address(),
+			CommandError::InvalidAddress(IpAddressError::V4(Ipv4Error::LeadingZero {
+				input: "010.0.0.1".to_string(),
+				position: 1,
+			})),
+		);
+
+		let fixture = parse(&["-n", "10.0.0.00", "-s", "8", "-a", "10.0.0.1", "--strict"]).unwrap();
+		assert_error(
+			fixture.target(),
+			CommandError::InvalidNetwork(Ipv4Error::LeadingZero {
+				input: "10.0.0.00".to_string(),
+				position: 4,
+			}),
+		);
+
+		let fixture = parse(&[
+			"--strict",
+			"-n",
+			"10.0.0.0",
+			"-s",
+			"0xff000000",
+			"-a",
+			"10.0.0.1",
+		])
+		.unwrap();
+		assert_error(
+			fixture.target(),
+			CommandError::InvalidMask(IPv4MaskError::FormatError("0xff000000".to_string())),
+		);
+	}
+
+	#[test]
+	fn parse_bare_address_cidr_test() {
+		let fixture = parse(&["-c", "10.0.0.1", "-a", "10.0.0.1"]).unwrap();
+		assert_eq!(format!("{}", fixture.target().unwrap()), "10.0.0.1/32");
+	}
+
+	#[test]
+	fn target_error_test() {
+		let fixture = parse(&["-c", "10.0.0.0/x", "-a", "10.0.0.1"]).unwrap();
+		assert_error(
+			fixture.target(),
+			CommandError::InvalidCidr(IpCidrError::V4(CidrError::PrefixError(
+				IPv4MaskError::FormatError("x".to_string()),
+			))),
+		);
+
+		let fixture = parse(&["-c", "10.0.0.256/8", "-a", "10.0.0.1"]).unwrap();
+		assert_error(
+			fixture.target(),
+			CommandError::InvalidCidr(IpCidrError::V4(CidrError::AddressError(
+				Ipv4Error::InvalidValue {
+					input: "10.0.0.256".to_string(),
+					position: 4,
+				},
+			))),
+		);
+
+		let fixture = parse(&["-c", "10.0.0.1/8", "-a", "10.0.0.1"]).unwrap();
+		assert_error(
+			fixture.target(),
+			CommandError::InvalidCidr(IpCidrError::V4(CidrError::InvalidAddressOrMask {
+				address: IPv4::try_from("10.0.0.1").unwrap(),
+				mask: IPv4Mask::new(8).unwrap(),
+			})),
+		);
+
+		let fixture = parse(&["-c", "2001:db8::1/32", "-a", "2001:db8::1"]).unwrap();
+		assert_error(
+			fixture.target(),
+			CommandError::InvalidCidr(IpCidrError::V6(Cidr6Error::InvalidAddressOrMask {
+				address: IPv6::try_from("2001:db8::1").unwrap(),
+				mask: IPv6Mask::new(32).unwrap(),
+			})),
+		);
+
+		let fixture = parse(&["-s", "255.0.0", "-n", "10.0.0.0", "-a", "10.0.0.1"]).unwrap();
+		assert_error(
+			fixture.target(),
+			CommandError::InvalidMask(IPv4MaskError::FormatError("255.0.0".to_string())),
+		);
+
+		let fixture = parse(&["-s", "255.0.255.0", "-n", "10.0.0.0", "-a", "10.0.0.1"]).unwrap();
+		assert_error(
+			fixture.target(),
+			CommandError::InvalidMask(IPv4MaskError::InvalidSubnetMask(
+				IPv4::try_from("255.0.255.0").unwrap(),
+			)),
+		);
+
+		let fixture = parse(&["-s", "255.0.0.0", "-n", "10.0.0", "-a", "10.0.0.1"]).unwrap();
+		assert_error(
+			fixture.target(),
+			CommandError::InvalidNetwork(Ipv4Error::InvalidFormat("10.0.0".to_string())),
+		);
+	}
+
+	#[test]
+	fn address_error_test() {
+		let fixture = parse(&["-c", "10.0.0.0/8", "-a", "10.0.0"]).unwrap();
+		assert_error(
+			fixture.address(),
+			CommandError::InvalidAddress(IpAddressError::V4(Ipv4Error::InvalidFormat(
+				"10.0.0".to_string(),
+			))),
+		);
+	}
+
+	#[test]
+	fn parse_ipv6_test() {
+		let fixture = parse(&["-c", "2001:db8::/32", "-a", "2001:db8::1"]).unwrap();
+
+		assert_eq!(format!("{}", fixture.target().unwrap()), "2001:db8::/32");
+		assert_eq!(
+			fixture.address().unwrap(),
+			IpAddress::try_from("2001:db8::1").unwrap()
+		);
+	}
 }
@@ -0,0 +1,164 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+use cidr_checker::{IPv4MaskError, IpAddressError, IpCidrError, Ipv4Error};
+
+#[derive(Debug)]
+pub enum CommandError {
+	UnknownOption(String),
+	MissingValue(String),
+	DuplicateOption(String),
+	ConflictingOptions,
+	MissingCidr,
+	MissingMask,
+	MissingNetwork,
+	MissingAddress,
+	MissingRules,
+	InvalidColumn(String),
+	InvalidAddress(IpAddressError),
+	InvalidNetwork(Ipv4Error),
+	InvalidMask(IPv4MaskError),
+	InvalidCidr(IpCidrError),
+	InvalidLine(String, usize, IpCidrError),
+	InvalidAddressLine(String, usize, IpAddressError),
+	MissingColumn(String, usize, usize),
+	Io(String, io::Error),
+}
+
+impl Display for CommandError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			CommandError::UnknownOption(option) => write!(f, "unknown option {}", option),
+			CommandError::MissingValue(option) => write!(f, "option {} requires a value", option),
+			CommandError::DuplicateOption(option) => {
+				write!(f, "option {} is given more than once", option)
+			}
+			CommandError::ConflictingOptions => write!(f, "-c cannot be combined with -n or -s"),
+			CommandError::MissingCidr => write!(f, "no network given: use -c, or -n with -s"),
+			CommandError::MissingMask => write!(f, "-n requires a subnet mask (-s)"),
+			CommandError::MissingNetwork => write!(f, "-s requires a network address (-n)"),
+			CommandError::MissingAddress => write!(f, "no address given: use -a"),
+			CommandError::MissingRules => write!(f, "no rules given: use -f"),
+			CommandError::InvalidColumn(column) => {
+				write!(f, "invalid column (-k): {} is not a number from 1", column)
+			}
+			CommandError::InvalidAddress(e) => write!(f, "invalid address (-a): {}", e),
+			CommandError::InvalidNetwork(e) => write!(f, "invalid network (-n): {}", e),
+			CommandError::InvalidMask(e) => write!(f, "invalid subnet mask (-s): {}", e),
+			CommandError::InvalidCidr(e) => write!(f, "invalid network: {}", e),
+			CommandError::InvalidLine(source, line, e) => write!(f, "{}:{}: {}", source, line, e),
+			CommandError::InvalidAddressLine(source, line, e) => {
+				write!(f, "{}:{}: {}", source, line, e)
+			}
+			CommandError::MissingColumn(source, line, column) => {
+				write!(f, "{}:{}: no column {}", source, line, column)
+			}
+			CommandError::Io(source, e) => write!(f, "cannot read {}: {}", source, e),
+		}
+	}
+}
+
+impl Error for CommandError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			CommandError::InvalidAddress(e) => Some(e),
+			CommandError::InvalidNetwork(e) => Some(e),
+			CommandError::InvalidMask(e) => Some(e),
+			CommandError::InvalidCidr(e) => Some(e),
+			CommandError::InvalidLine(_, _, e) => Some(e),
+			CommandError::InvalidAddressLine(_, _, e) => Some(e),
+			CommandError::Io(_, e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::error::Error;
+	use std::io;
+
+	use cidr_checker::{CidrError, IPv4MaskError, IpAddressError, IpCidrError, Ipv4Error};
+
+	use crate::command_error::CommandError;
+
+	#[test]
+	fn display_test() {
+		let actual = format!("{}", CommandError::UnknownOption("-x".to_string()));
+		assert_eq!(actual, "unknown option -x");
+
+		let actual = format!("{}", CommandError::ConflictingOptions);
+		assert_eq!(actual, "-c cannot be combined with -n or -s");
+
+		let actual = format!(
+			"{}",
+			CommandError::InvalidAddress(IpAddressError::V4(Ipv4Error::InvalidFormat(
+				"10.0".to_string()
+			)))
+		);
+		assert_eq!(
+			actual,
+			"invalid address (-a): \"10.0\" is not an IPv4 address: expected four dot-separated octets"
+		);
+
+		let actual = format!(
+			"{}",
+			CommandError::InvalidMask(IPv4MaskError::FormatError("x".to_string()))
+		);
+		assert_eq!(
+			actual,
+			"invalid subnet mask (-s): \"x\" is not a prefix length or subnet mask"
+		);
+
+		let actual = format!(
+			"{}",
+			CommandError::InvalidLine(
+				"rules.txt".to_string(),
+				3,
+				IpCidrError::V4(CidrError::PrefixError(IPv4MaskError::CidrOutOfRange(33)))
+			)
+		);
+		assert_eq!(
+			actual,
+			"rules.txt:3: prefix length 33 is out of range (expected 0 to 32)"
+		);
+
+		let actual = format!(
+			"{}",
+			CommandError::InvalidAddressLine(
+				"-".to_string(),
+				7,
+				IpAddressError::V4(Ipv4Error::InvalidFormat("10.0.0".to_string()))
+			)
+		);
+		assert_eq!(
+			actual,
+			"-:7: \"10.0.0\" is not an IPv4 address: expected four dot-separated octets"
+		);
+
+		let actual = format!(
+			"{}",
+			CommandError::MissingColumn("log.csv".to_string(), 2, 3)
+		);
+		assert_eq!(actual, "log.csv:2: no column 3");
+
+		let actual = format!(
+			"{}",
+			CommandError::Io(
+				"rules.txt".to_string(),
+				io::Error::new(io::ErrorKind::NotFound, "not found")
+			)
+		);
+		assert_eq!(actual, "cannot read rules.txt: not found");
+	}
+
+	#[test]
+	fn source_test() {
+		let fixture = CommandError::InvalidNetwork(Ipv4Error::IndexOutOfRange(4));
+		assert!(fixture.source().is_some());
+
+		let fixture = CommandError::MissingAddress;
+		assert!(fixture.source().is_none());
+	}
+}
@@ -0,0 +1,192 @@
+use std::fmt::{Display, Formatter};
+
+use crate::cidr6_error::Cidr6Error;
+use crate::cidr_error::CidrError;
+use crate::ip_address_error::IpAddressError;
+use crate::ip_cidr_error::IpCidrError;
+use crate::ipv4_error::Ipv4Error;
+use crate::ipv4_mask_error::IPv4MaskError;
+use crate::ipv4_network_range_error::IPv4NetworkRangeError;
+use crate::ipv6_error::Ipv6Error;
+use crate::ipv6_mask_error::IPv6MaskError;
+use crate::ipv6_network_range_error::IPv6NetworkRangeError;
+
+/// Any error this crate produces. Every specific error converts into it, so a
+/// function can `?` through addresses, masks and CIDRs of both families and
+/// still return one type. Displays exactly as the wrapped error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+	Ipv4(Ipv4Error),
+	Ipv4Mask(IPv4MaskError),
+	Cidr(CidrError),
+	Ipv4NetworkRange(IPv4NetworkRangeError),
+	Ipv6(Ipv6Error),
+	Ipv6Mask(IPv6MaskError),
+	Cidr6(Cidr6Error),
+	Ipv6NetworkRange(IPv6NetworkRangeError),
+}
+
+impl Error {
+	fn inner(&self) -> &(dyn std::error::Error + 'static) {
+		match self {
+			Error::Ipv4(e) => e,
+			Error::Ipv4Mask(e) => e,
+			Error::Cidr(e) => e,
+			Error::Ipv4NetworkRange(e) => e,
+			Error::Ipv6(e) => e,
+			Error::Ipv6Mask(e) => e,
+			Error::Cidr6(e) => e,
+			Error::Ipv6NetworkRange(e) => e,
+		}
+	}
+}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.inner())
+	}
+}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		self.inner().source()
+	}
+}
+
+impl From<Ipv4Error> for Error {
+	fn from(value: Ipv4Error) -> Self {
+		Error::Ipv4(value)
+	}
+}
+
+impl From<IPv4MaskError> for Error {
+	fn from(value: IPv4MaskError) -> Self {
+		Error::Ipv4Mask(value)
+	}
+}
+
+impl From<CidrError> for Error {
+	fn from(value: CidrError) -> Self {
+		Error::Cidr(value)
+	}
+}
+
+impl From<IPv4NetworkRangeError> for Error {
+	fn from(value: IPv4NetworkRangeError) -> Self {
+		Error::Ipv4NetworkRange(value)
+	}
+}
+
+impl From<Ipv6Error> for Error {
+	fn from(value: Ipv6Error) -> Self {
+		Error::Ipv6(value)
+	}
+}
+
+impl From<IPv6MaskError> for Error {
+	fn from(value: IPv6MaskError) -> Self {
+		Error::Ipv6Mask(value)
+	}
+}
+
+impl From<Cidr6Error> for Error {
+	fn from(value: Cidr6Error) -> Self {
+		Error::Cidr6(value)
+	}
+}
+
+impl From<IPv6NetworkRangeError> for Error {
+	fn from(value: IPv6NetworkRangeError) -> Self {
+		Error::Ipv6NetworkRange(value)
+	}
+}
+
+impl From<IpAddressError> for Error {
+	fn from(value: IpAddressError) -> Self {
+		match value {
+			IpAddressError::V4(e) => Error::Ipv4(e),
+			IpAddressError::V6(e) => Error::Ipv6(e),
+		}
+	}
+}
+
+impl From<IpCidrError> for Error {
+	fn from(value: IpCidrError) -> Self {
+		match value {
+			IpCidrError::V4(e) => Error::Cidr(e),
+			IpCidrError::V6(e) => Error::Cidr6(e),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::cidr::Cidr;
+	use crate::cidr_error::CidrError;
+	use crate::error::Error;
+	use crate::ip_cidr::IpCidr;
+	use crate::ipv4::IPv4;
+	use crate::ipv4_error::Ipv4Error;
+	use crate::ipv4_mask::IPv4Mask;
+	use crate::ipv4_mask_error::IPv4MaskError;
+
+	fn parse(cidr: &str, address: &str) -> Result<bool, Error> {
+		let cidr = Cidr::try_from(cidr)?;
+		let address = IPv4::try_from(address)?;
+		Ok(cidr.contains(&address))
+	}
+
+	#[test]
+	fn question_mark_test() {
+		assert_eq!(parse("10.0.0.0/8", "10.1.2.3"), Ok(true));
+
+		assert_eq!(
+			parse("10.0.0.0/8", "10.1.2"),
+			Err(Error::Ipv4(Ipv4Error::InvalidFormat("10.1.2".to_string())))
+		);
+
+		assert_eq!(
+			parse("10.0.0.0/33", "10.1.2.3"),
+			Err(Error::Cidr(CidrError::PrefixError(
+				IPv4MaskError::CidrOutOfRange(33)
+			)))
+		);
+	}
+
+	#[test]
+	fn from_family_test() {
+		let actual: Error = IpCidr::try_from("2001:db8::/129").unwrap_err().into();
+		assert!(matches!(actual, Error::Cidr6(_)));
+
+		let actual: Error = IpCidr::try_from("10.0.0.0/33").unwrap_err().into();
+		assert!(matches!(actual, Error::Cidr(_)));
+
+		let actual: Error = IPv4Mask::new(33).unwrap_err().into();
+		assert!(matches!(actual, Error::Ipv4Mask(_)));
+	}
+
+	#[test]
+	fn display_test() {
+		let actual = Error::from(Ipv4Error::InvalidValue {
+			input: "10.0.0.256".to_string(),
+			position: 4,
+		});
+		assert_eq!(
+			format!("{}", actual),
+			"octet 4 of \"10.0.0.256\" (\"256\") is not a number from 0 to 255"
+		);
+	}
+
+	#[test]
+	fn boxed_error_test() {
+		fn run() -> Result<Cidr, Box<dyn std::error::Error>> {
+			Ok(Cidr::try_from("10.0.0.1/8")?)
+		}
+
+		let actual = run().unwrap_err();
+		assert_eq!(
+			actual.to_string(),
+			"10.0.0.1/8 has host bits set (the network is 10.0.0.0/8)"
+		);
+	}
+}
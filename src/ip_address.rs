@@ -0,0 +1,224 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use crate::ip_address_error::IpAddressError;
+use crate::ipv4::IPv4;
+use crate::ipv4_parse_mode::IPv4ParseMode;
+use crate::ipv6::IPv6;
+
+/// An address of either family. IPv4 addresses order before IPv6 ones.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub enum IpAddress {
+	V4(IPv4),
+	V6(IPv6),
+}
+
+impl IpAddress {
+	/// Parses `value` like `try_from(&str)`, reading an IPv4 address as `mode`
+	/// allows. IPv6 addresses are not affected.
+	pub fn parse_with(value: &str, mode: IPv4ParseMode) -> Result<IpAddress, IpAddressError> {
+		if value.contains(':') {
+			IPv6::try_from(value)
+				.map(IpAddress::V6)
+				.map_err(IpAddressError::V6)
+		} else {
+			IPv4::parse_with(value, mode)
+				.map(IpAddress::V4)
+				.map_err(IpAddressError::V4)
+		}
+	}
+
+	/// Unwraps an IPv4-mapped IPv6 address (`::ffff:a.b.c.d`) into the IPv4
+	/// address it carries, like `std::net::IpAddr::to_canonical`. Nothing in
+	/// the crate does this implicitly, so IPv4 blocks never contain a mapped
+	/// address until it has been passed through here.
+	pub fn to_canonical(&self) -> IpAddress {
+		match self {
+			IpAddress::V6(address) if address.to_u128() >> 32 == 0xffff => {
+				IpAddress::V4(IPv4::from(address.to_u128() as u32))
+			}
+			_ => *self,
+		}
+	}
+
+	pub fn is_ipv4(&self) -> bool {
+		matches!(self, IpAddress::V4(_))
+	}
+
+	pub fn is_ipv6(&self) -> bool {
+		matches!(self, IpAddress::V6(_))
+	}
+
+	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			IpAddress::V4(address) => write!(f, "{}", address),
+			IpAddress::V6(address) => write!(f, "{}", address),
+		}
+	}
+}
+
+impl From<IPv4> for IpAddress {
+	fn from(value: IPv4) -> Self {
+		IpAddress::V4(value)
+	}
+}
+
+impl From<IPv6> for IpAddress {
+	fn from(value: IPv6) -> Self {
+		IpAddress::V6(value)
+	}
+}
+
+impl From<IpAddr> for IpAddress {
+	fn from(value: IpAddr) -> Self {
+		match value {
+			IpAddr::V4(address) => IpAddress::V4(address.into()),
+			IpAddr::V6(address) => IpAddress::V6(address.into()),
+		}
+	}
+}
+
+impl From<IpAddress> for IpAddr {
+	fn from(value: IpAddress) -> Self {
+		match value {
+			IpAddress::V4(address) => address.into(),
+			IpAddress::V6(address) => address.into(),
+		}
+	}
+}
+
+impl TryFrom<&str> for IpAddress {
+	type Error = IpAddressError;
+
+	/// Anything containing a `:` is parsed as IPv6, everything else as IPv4.
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		IpAddress::parse_with(value, IPv4ParseMode::Lenient)
+	}
+}
+
+impl FromStr for IpAddress {
+	type Err = IpAddressError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		IpAddress::try_from(s)
+	}
+}
+
+impl Debug for IpAddress {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+impl Display for IpAddress {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+	use crate::ip_address::IpAddress;
+	use crate::ip_address_error::IpAddressError;
+	use crate::ipv4::IPv4;
+	use crate::ipv4_error::Ipv4Error;
+	use crate::ipv4_parse_mode::IPv4ParseMode;
+	use crate::ipv6::IPv6;
+
+	#[test]
+	fn parse_with_test() {
+		let actual = IpAddress::parse_with("10.0.0.1", IPv4ParseMode::Strict).unwrap();
+		assert_eq!(actual, IpAddress::try_from("10.0.0.1").unwrap());
+
+		let actual = IpAddress::parse_with("0xa000001", IPv4ParseMode::InetAton).unwrap();
+		assert_eq!(actual, IpAddress::try_from("10.0.0.1").unwrap());
+
+		let actual = IpAddress::parse_with("2001:db8::1", IPv4ParseMode::Strict).unwrap();
+		assert!(actual.is_ipv6());
+
+		assert_eq!(
+			IpAddress::parse_with("10.0.0.01", IPv4ParseMode::Strict),
+			Err(IpAddressError::V4(Ipv4Error::LeadingZero {
+				input: "10.0.0.01".to_string(),
+				position: 4,
+			}))
+		);
+	}
+
+	#[test]
+	fn to_canonical_test() {
+		let actual = IpAddress::try_from("::ffff:10.0.0.1")
+			.unwrap()
+			.to_canonical();
+		assert_eq!(actual, IpAddress::try_from("10.0.0.1").unwrap());
+
+		for value in [
+			"10.0.0.1",
+			"::10.0.0.1",
+			"::1",
+			"::",
+			"2001:db8::ffff:a00:1",
+		] {
+			let fixture = IpAddress::try_from(value).unwrap();
+			assert_eq!(fixture.to_canonical(), fixture);
+		}
+	}
+
+	#[test]
+	fn try_from_str_test() {
+		let actual = IpAddress::try_from("192.168.10.1").unwrap();
+		assert_eq!(
+			actual,
+			IpAddress::V4(IPv4::try_from("192.168.10.1").unwrap())
+		);
+		assert!(actual.is_ipv4());
+
+		let actual = IpAddress::try_from("2001:db8::1").unwrap();
+		assert_eq!(
+			actual,
+			IpAddress::V6(IPv6::try_from("2001:db8::1").unwrap())
+		);
+		assert!(actual.is_ipv6());
+
+		assert!(matches!(
+			IpAddress::try_from("192.168.10.256"),
+			Err(IpAddressError::V4(_))
+		));
+		assert!(matches!(
+			IpAddress::try_from("2001:db8:::1"),
+			Err(IpAddressError::V6(_))
+		));
+		assert!(matches!(
+			"::ffff:1.2.3.4".parse::<IpAddress>(),
+			Ok(IpAddress::V6(_))
+		));
+	}
+
+	#[test]
+	fn std_net_test() {
+		let v4 = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+		let v6 = IpAddr::V6(Ipv6Addr::LOCALHOST);
+
+		assert_eq!(IpAddr::from(IpAddress::from(v4)), v4);
+		assert_eq!(IpAddr::from(IpAddress::from(v6)), v6);
+	}
+
+	#[test]
+	fn display_test() {
+		for value in ["10.0.0.1", "2001:db8::1", "::ffff:10.0.0.1"] {
+			let fixture = IpAddress::try_from(value).unwrap();
+			assert_eq!(format!("{}", fixture), value);
+			assert_eq!(format!("{:?}", fixture), value);
+		}
+	}
+
+	#[test]
+	fn ord_test() {
+		let v4 = IpAddress::try_from("255.255.255.255").unwrap();
+		let v6 = IpAddress::try_from("::").unwrap();
+		assert!(v4 < v6);
+	}
+}
@@ -0,0 +1,54 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::ipv4_error::Ipv4Error;
+use crate::ipv6_error::Ipv6Error;
+
+/// Transparent over the family-specific error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IpAddressError {
+	V4(Ipv4Error),
+	V6(Ipv6Error),
+}
+
+impl Display for IpAddressError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			IpAddressError::V4(e) => write!(f, "{}", e),
+			IpAddressError::V6(e) => write!(f, "{}", e),
+		}
+	}
+}
+
+impl Error for IpAddressError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			IpAddressError::V4(e) => e.source(),
+			IpAddressError::V6(e) => e.source(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::ip_address_error::IpAddressError;
+	use crate::ipv4_error::Ipv4Error;
+	use crate::ipv6_error::Ipv6Error;
+
+	#[test]
+	fn display_test() {
+		let fixture = IpAddressError::V4(Ipv4Error::InvalidFormat("10.0".to_string()));
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"\"10.0\" is not an IPv4 address: expected four dot-separated octets"
+		);
+
+		let fixture = IpAddressError::V6(Ipv6Error::ZoneIdNotSupported("fe80::1%1".to_string()));
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"\"fe80::1%1\" has a zone ID, which is not supported"
+		);
+	}
+}
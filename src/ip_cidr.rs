@@ -0,0 +1,282 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::cidr::Cidr;
+use crate::cidr6::Cidr6;
+use crate::ip_address::IpAddress;
+use crate::ip_cidr_error::IpCidrError;
+use crate::ipv4_parse_mode::IPv4ParseMode;
+use crate::prefix::Prefix;
+
+/// A CIDR block of either family. IPv4 blocks order before IPv6 ones, and
+/// blocks of different families never contain or overlap each other. That
+/// includes IPv4-mapped addresses: `10.0.0.0/8` does not contain
+/// `::ffff:10.0.0.1` unless the address is first passed through
+/// `IpAddress::to_canonical`.
+///
+/// ```
+/// use cidr_checker::{IpAddress, IpCidr, Prefix};
+///
+/// let cidr = IpCidr::try_from("2001:db8::/32")?;
+/// assert!(cidr.contains(&IpAddress::try_from("2001:db8::1")?));
+/// assert!(!cidr.contains(&IpAddress::try_from("192.0.2.1")?));
+/// # Ok::<(), cidr_checker::Error>(())
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub enum IpCidr {
+	V4(Cidr),
+	V6(Cidr6),
+}
+
+impl IpCidr {
+	/// Parses `value` like `try_from(&str)`, reading an IPv4 block as `mode`
+	/// allows (see `Cidr::parse_with`). IPv6 blocks are not affected.
+	pub fn parse_with(value: &str, mode: IPv4ParseMode) -> Result<IpCidr, IpCidrError> {
+		if value.contains(':') {
+			Cidr6::try_from(value)
+				.map(IpCidr::V6)
+				.map_err(IpCidrError::V6)
+		} else {
+			Cidr::parse_with(value, mode)
+				.map(IpCidr::V4)
+				.map_err(IpCidrError::V4)
+		}
+	}
+
+	pub fn is_ipv4(&self) -> bool {
+		matches!(self, IpCidr::V4(_))
+	}
+
+	pub fn is_ipv6(&self) -> bool {
+		matches!(self, IpCidr::V6(_))
+	}
+
+	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			IpCidr::V4(cidr) => write!(f, "{}", cidr),
+			IpCidr::V6(cidr) => write!(f, "{}", cidr),
+		}
+	}
+}
+
+impl Prefix for IpCidr {
+	type Address = IpAddress;
+
+	fn contains(&self, address: &IpAddress) -> bool {
+		match (self, address) {
+			(IpCidr::V4(cidr), IpAddress::V4(address)) => cidr.contains(address),
+			(IpCidr::V6(cidr), IpAddress::V6(address)) => cidr.contains(address),
+			_ => false,
+		}
+	}
+
+	fn network(&self) -> IpAddress {
+		match self {
+			IpCidr::V4(cidr) => IpAddress::V4(Prefix::network(cidr)),
+			IpCidr::V6(cidr) => IpAddress::V6(Prefix::network(cidr)),
+		}
+	}
+
+	fn last(&self) -> IpAddress {
+		match self {
+			IpCidr::V4(cidr) => IpAddress::V4(cidr.last()),
+			IpCidr::V6(cidr) => IpAddress::V6(cidr.last()),
+		}
+	}
+
+	fn prefix_len(&self) -> u8 {
+		match self {
+			IpCidr::V4(cidr) => cidr.prefix_len(),
+			IpCidr::V6(cidr) => cidr.prefix_len(),
+		}
+	}
+
+	fn overlaps(&self, other: &IpCidr) -> bool {
+		match (self, other) {
+			(IpCidr::V4(cidr), IpCidr::V4(other)) => cidr.overlaps(other),
+			(IpCidr::V6(cidr), IpCidr::V6(other)) => cidr.overlaps(other),
+			_ => false,
+		}
+	}
+
+	fn supernet(&self) -> Option<IpCidr> {
+		match self {
+			IpCidr::V4(cidr) => cidr.supernet().map(IpCidr::V4),
+			IpCidr::V6(cidr) => cidr.supernet().map(IpCidr::V6),
+		}
+	}
+
+	fn halves(&self) -> Option<(IpCidr, IpCidr)> {
+		match self {
+			IpCidr::V4(cidr) => cidr.halves().map(|(l, u)| (IpCidr::V4(l), IpCidr::V4(u))),
+			IpCidr::V6(cidr) => cidr.halves().map(|(l, u)| (IpCidr::V6(l), IpCidr::V6(u))),
+		}
+	}
+}
+
+impl From<Cidr> for IpCidr {
+	fn from(value: Cidr) -> Self {
+		IpCidr::V4(value)
+	}
+}
+
+impl From<Cidr6> for IpCidr {
+	fn from(value: Cidr6) -> Self {
+		IpCidr::V6(value)
+	}
+}
+
+impl TryFrom<&str> for IpCidr {
+	type Error = IpCidrError;
+
+	/// Anything containing a `:` is parsed as `Cidr6`, everything else as `Cidr`.
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		IpCidr::parse_with(value, IPv4ParseMode::Lenient)
+	}
+}
+
+impl FromStr for IpCidr {
+	type Err = IpCidrError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		IpCidr::try_from(s)
+	}
+}
+
+impl Debug for IpCidr {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+impl Display for IpCidr {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::ip_address::IpAddress;
+	use crate::ip_cidr::IpCidr;
+	use crate::ip_cidr_error::IpCidrError;
+	use crate::ipv4_parse_mode::IPv4ParseMode;
+	use crate::prefix::Prefix;
+
+	fn cidr(value: &str) -> IpCidr {
+		IpCidr::try_from(value).unwrap()
+	}
+
+	fn addr(value: &str) -> IpAddress {
+		IpAddress::try_from(value).unwrap()
+	}
+
+	#[test]
+	fn parse_with_test() {
+		let actual = IpCidr::parse_with("10.0.0.0/8", IPv4ParseMode::Strict).unwrap();
+		assert_eq!(actual, cidr("10.0.0.0/8"));
+
+		let actual = IpCidr::parse_with("2001:db8::/32", IPv4ParseMode::Strict).unwrap();
+		assert_eq!(actual, cidr("2001:db8::/32"));
+
+		let actual = IpCidr::parse_with("10.0.0.0/08", IPv4ParseMode::Lenient).unwrap();
+		assert_eq!(actual, cidr("10.0.0.0/8"));
+
+		for value in ["010.0.0.0/8", "10.0.0.0/08", "10.0.0.0/+8"] {
+			assert!(matches!(
+				IpCidr::parse_with(value, IPv4ParseMode::Strict),
+				Err(IpCidrError::V4(_))
+			));
+		}
+	}
+
+	#[test]
+	fn try_from_str_test() {
+		assert!(cidr("192.168.10.0/24").is_ipv4());
+		assert!(cidr("2001:db8::/32").is_ipv6());
+		assert!(cidr("::ffff:0:0/96").is_ipv6());
+
+		assert!(matches!(
+			IpCidr::try_from("192.168.10.1/24"),
+			Err(IpCidrError::V4(_))
+		));
+		assert!(matches!(
+			IpCidr::try_from("2001:db8::1/32"),
+			Err(IpCidrError::V6(_))
+		));
+	}
+
+	#[test]
+	fn contains_test() {
+		let v4 = cidr("10.0.0.0/8");
+		let v6 = cidr("2001:db8::/32");
+
+		assert!(v4.contains(&addr("10.1.2.3")));
+		assert!(!v4.contains(&addr("11.0.0.0")));
+		assert!(!v4.contains(&addr("::ffff:10.1.2.3")));
+		assert!(v4.contains(&addr("::ffff:10.1.2.3").to_canonical()));
+
+		assert!(v6.contains(&addr("2001:db8::1")));
+		assert!(!v6.contains(&addr("10.1.2.3")));
+
+		assert!(!cidr("0.0.0.0/0").contains(&addr("::")));
+		assert!(!cidr("::/0").contains(&addr("0.0.0.0")));
+	}
+
+	#[test]
+	fn prefix_test() {
+		let v4 = cidr("192.168.10.0/24");
+		assert_eq!(v4.network(), addr("192.168.10.0"));
+		assert_eq!(v4.last(), addr("192.168.10.255"));
+		assert_eq!(v4.prefix_len(), 24);
+		assert_eq!(v4.supernet(), Some(cidr("192.168.10.0/23")));
+
+		let v6 = cidr("2001:db8::/64");
+		assert_eq!(v6.network(), addr("2001:db8::"));
+		assert_eq!(v6.last(), addr("2001:db8::ffff:ffff:ffff:ffff"));
+		assert_eq!(v6.prefix_len(), 64);
+		assert_eq!(v6.supernet(), Some(cidr("2001:db8::/63")));
+
+		assert_eq!(cidr("::/0").supernet(), None);
+	}
+
+	#[test]
+	fn overlaps_test() {
+		assert!(cidr("10.0.0.0/8").overlaps(&cidr("10.1.0.0/16")));
+		assert!(cidr("2001:db8::/64").overlaps(&cidr("2001:db8::/32")));
+		assert!(!cidr("0.0.0.0/0").overlaps(&cidr("::/0")));
+
+		assert!(cidr("10.0.0.0/8").contains_prefix(&cidr("10.1.0.0/16")));
+		assert!(!cidr("10.1.0.0/16").contains_prefix(&cidr("10.0.0.0/8")));
+		assert!(!cidr("::/0").contains_prefix(&cidr("10.0.0.0/8")));
+	}
+
+	#[test]
+	fn display_test() {
+		for value in ["10.0.0.0/8", "2001:db8::/32"] {
+			assert_eq!(format!("{}", cidr(value)), value);
+			assert_eq!(format!("{:?}", cidr(value)), value);
+		}
+	}
+
+	#[test]
+	fn ord_test() {
+		let mut actual = vec![
+			cidr("2001:db8::/32"),
+			cidr("10.0.0.0/8"),
+			cidr("::/0"),
+			cidr("0.0.0.0/0"),
+		];
+		actual.sort();
+
+		assert_eq!(
+			actual,
+			[
+				cidr("0.0.0.0/0"),
+				cidr("10.0.0.0/8"),
+				cidr("::/0"),
+				cidr("2001:db8::/32"),
+			]
+		);
+	}
+}
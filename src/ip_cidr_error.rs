@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::cidr6_error::Cidr6Error;
+use crate::cidr_error::CidrError;
+
+/// Transparent over the family-specific error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IpCidrError {
+	V4(CidrError),
+	V6(Cidr6Error),
+}
+
+impl Display for IpCidrError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			IpCidrError::V4(e) => write!(f, "{}", e),
+			IpCidrError::V6(e) => write!(f, "{}", e),
+		}
+	}
+}
+
+impl Error for IpCidrError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			IpCidrError::V4(e) => e.source(),
+			IpCidrError::V6(e) => e.source(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::cidr6_error::Cidr6Error;
+	use crate::cidr_error::CidrError;
+	use crate::ip_cidr_error::IpCidrError;
+	use crate::ipv4_mask_error::IPv4MaskError;
+	use crate::ipv6_error::Ipv6Error;
+
+	#[test]
+	fn display_test() {
+		let fixture = IpCidrError::V4(CidrError::PrefixError(IPv4MaskError::CidrOutOfRange(33)));
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"prefix length 33 is out of range (expected 0 to 32)"
+		);
+
+		let fixture = IpCidrError::V6(Cidr6Error::AddressError(Ipv6Error::InvalidFormat(
+			"::1::".to_string(),
+		)));
+		let actual = format!("{}", fixture);
+		assert_eq!(actual, "\"::1::\" is not an IPv6 address");
+	}
+}
@@ -0,0 +1,205 @@
+use crate::cidr6::Cidr6;
+use crate::cidr_map::CidrMap;
+use crate::ip_address::IpAddress;
+use crate::ip_cidr::IpCidr;
+
+/// A dual-stack `CidrMap`: one trie per family behind a single `IpCidr` API.
+pub struct IpCidrMap<V> {
+	v4: CidrMap<V>,
+	v6: CidrMap<V, Cidr6>,
+}
+
+impl<V> IpCidrMap<V> {
+	pub fn new() -> IpCidrMap<V> {
+		IpCidrMap {
+			v4: CidrMap::new(),
+			v6: CidrMap::new(),
+		}
+	}
+
+	pub fn len(&self) -> usize {
+		self.v4.len() + self.v6.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.v4.is_empty() && self.v6.is_empty()
+	}
+
+	/// Returns the previous value when `cidr` was already present.
+	pub fn insert(&mut self, cidr: IpCidr, value: V) -> Option<V> {
+		match cidr {
+			IpCidr::V4(cidr) => self.v4.insert(cidr, value),
+			IpCidr::V6(cidr) => self.v6.insert(cidr, value),
+		}
+	}
+
+	pub fn remove(&mut self, cidr: &IpCidr) -> Option<V> {
+		match cidr {
+			IpCidr::V4(cidr) => self.v4.remove(cidr),
+			IpCidr::V6(cidr) => self.v6.remove(cidr),
+		}
+	}
+
+	/// Exact-match lookup.
+	pub fn get(&self, cidr: &IpCidr) -> Option<&V> {
+		match cidr {
+			IpCidr::V4(cidr) => self.v4.get(cidr),
+			IpCidr::V6(cidr) => self.v6.get(cidr),
+		}
+	}
+
+	pub fn contains_key(&self, cidr: &IpCidr) -> bool {
+		self.get(cidr).is_some()
+	}
+
+	/// The most specific entry of the same family containing `address`. An
+	/// IPv4-mapped address only matches IPv6 entries; see
+	/// `IpAddress::to_canonical`.
+	pub fn longest_match(&self, address: &IpAddress) -> Option<(IpCidr, &V)> {
+		match address {
+			IpAddress::V4(address) => self
+				.v4
+				.longest_match(address)
+				.map(|(cidr, value)| (IpCidr::V4(cidr), value)),
+			IpAddress::V6(address) => self
+				.v6
+				.longest_match(address)
+				.map(|(cidr, value)| (IpCidr::V6(cidr), value)),
+		}
+	}
+
+	/// Every entry containing `address`, from the least to the most specific.
+	pub fn all_matches(&self, address: &IpAddress) -> Vec<(IpCidr, &V)> {
+		match address {
+			IpAddress::V4(address) => self
+				.v4
+				.all_matches(address)
+				.into_iter()
+				.map(|(cidr, value)| (IpCidr::V4(cidr), value))
+				.collect(),
+			IpAddress::V6(address) => self
+				.v6
+				.all_matches(address)
+				.into_iter()
+				.map(|(cidr, value)| (IpCidr::V6(cidr), value))
+				.collect(),
+		}
+	}
+
+	/// Iterates entries in `IpCidr` order: IPv4 blocks first, then IPv6.
+	pub fn iter(&self) -> impl Iterator<Item = (IpCidr, &V)> {
+		let v4 = self
+			.v4
+			.iter()
+			.map(|(cidr, value)| (IpCidr::V4(cidr), value));
+		let v6 = self
+			.v6
+			.iter()
+			.map(|(cidr, value)| (IpCidr::V6(cidr), value));
+		v4.chain(v6)
+	}
+}
+
+impl<V> Default for IpCidrMap<V> {
+	fn default() -> Self {
+		IpCidrMap::new()
+	}
+}
+
+impl<V> FromIterator<(IpCidr, V)> for IpCidrMap<V> {
+	fn from_iter<T: IntoIterator<Item = (IpCidr, V)>>(iter: T) -> Self {
+		let mut map = IpCidrMap::new();
+		for (cidr, value) in iter {
+			map.insert(cidr, value);
+		}
+		map
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::ip_address::IpAddress;
+	use crate::ip_cidr::IpCidr;
+	use crate::ip_cidr_map::IpCidrMap;
+
+	fn cidr(value: &str) -> IpCidr {
+		IpCidr::try_from(value).unwrap()
+	}
+
+	fn addr(value: &str) -> IpAddress {
+		IpAddress::try_from(value).unwrap()
+	}
+
+	fn fixture() -> IpCidrMap<&'static str> {
+		[
+			(cidr("0.0.0.0/0"), "v4 default"),
+			(cidr("10.0.0.0/8"), "corp"),
+			(cidr("::/0"), "v6 default"),
+			(cidr("2001:db8::/32"), "doc"),
+		]
+		.into_iter()
+		.collect()
+	}
+
+	#[test]
+	fn longest_match_test() {
+		let fixture = fixture();
+
+		assert_eq!(
+			fixture.longest_match(&addr("10.1.1.1")),
+			Some((cidr("10.0.0.0/8"), &"corp"))
+		);
+		assert_eq!(
+			fixture.longest_match(&addr("8.8.8.8")),
+			Some((cidr("0.0.0.0/0"), &"v4 default"))
+		);
+		assert_eq!(
+			fixture.longest_match(&addr("2001:db8::1")),
+			Some((cidr("2001:db8::/32"), &"doc"))
+		);
+		assert_eq!(
+			fixture.longest_match(&addr("::ffff:10.1.1.1")),
+			Some((cidr("::/0"), &"v6 default"))
+		);
+
+		let actual = fixture.all_matches(&addr("2001:db8::1"));
+		assert_eq!(
+			actual,
+			[
+				(cidr("::/0"), &"v6 default"),
+				(cidr("2001:db8::/32"), &"doc")
+			]
+		);
+	}
+
+	#[test]
+	fn insert_and_remove_test() {
+		let mut fixture = fixture();
+		assert_eq!(fixture.len(), 4);
+
+		assert_eq!(fixture.insert(cidr("::/0"), "any"), Some("v6 default"));
+		assert_eq!(fixture.get(&cidr("::/0")), Some(&"any"));
+		assert_eq!(fixture.get(&cidr("0.0.0.0/0")), Some(&"v4 default"));
+
+		assert_eq!(fixture.remove(&cidr("0.0.0.0/0")), Some("v4 default"));
+		assert!(!fixture.contains_key(&cidr("0.0.0.0/0")));
+		assert!(fixture.contains_key(&cidr("::/0")));
+		assert_eq!(fixture.longest_match(&addr("8.8.8.8")), None);
+		assert_eq!(fixture.len(), 3);
+
+		let mut fixture = IpCidrMap::new();
+		assert!(fixture.is_empty());
+		fixture.insert(cidr("2001:db8::/32"), 1);
+		assert!(!fixture.is_empty());
+	}
+
+	#[test]
+	fn iter_test() {
+		let actual: Vec<_> = fixture().iter().map(|(c, _)| c).collect();
+		let mut expected = actual.clone();
+		expected.sort();
+
+		assert_eq!(actual, expected);
+		assert_eq!(actual.len(), 4);
+	}
+}
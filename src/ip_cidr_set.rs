@@ -0,0 +1,264 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::cidr6::Cidr6;
+use crate::cidr_set::CidrSet;
+use crate::ip_address::IpAddress;
+use crate::ip_cidr::IpCidr;
+
+/// A dual-stack `CidrSet`: one set per family behind a single `IpCidr` API.
+/// The families never interact, so removing an IPv4 block leaves the IPv6
+/// half untouched and vice versa, and IPv4 blocks do not contain IPv4-mapped
+/// addresses (see `IpAddress::to_canonical`).
+///
+/// ```
+/// use cidr_checker::{IpAddress, IpCidr, IpCidrSet};
+///
+/// let mut set: IpCidrSet = ["10.0.0.0/8", "2001:db8::/32"]
+///     .into_iter()
+///     .map(IpCidr::try_from)
+///     .collect::<Result<_, _>>()?;
+/// set.remove(IpCidr::try_from("2001:db8::/33")?);
+///
+/// assert!(set.contains(&IpAddress::try_from("10.1.2.3")?));
+/// assert!(!set.contains(&IpAddress::try_from("2001:db8::1")?));
+/// assert_eq!(set.to_string(), "{10.0.0.0/8, 2001:db8:8000::/33}");
+/// # Ok::<(), cidr_checker::Error>(())
+/// ```
+#[derive(Eq, PartialEq, Hash, Clone, Default)]
+pub struct IpCidrSet {
+	v4: CidrSet,
+	v6: CidrSet<Cidr6>,
+}
+
+impl IpCidrSet {
+	pub fn new() -> IpCidrSet {
+		IpCidrSet {
+			v4: CidrSet::new(),
+			v6: CidrSet::new(),
+		}
+	}
+
+	pub fn insert(&mut self, cidr: IpCidr) {
+		match cidr {
+			IpCidr::V4(cidr) => self.v4.insert(cidr),
+			IpCidr::V6(cidr) => self.v6.insert(cidr),
+		}
+	}
+
+	pub fn remove(&mut self, cidr: IpCidr) {
+		match cidr {
+			IpCidr::V4(cidr) => self.v4.remove(cidr),
+			IpCidr::V6(cidr) => self.v6.remove(cidr),
+		}
+	}
+
+	pub fn contains(&self, address: &IpAddress) -> bool {
+		match address {
+			IpAddress::V4(address) => self.v4.contains(address),
+			IpAddress::V6(address) => self.v6.contains(address),
+		}
+	}
+
+	pub fn union(&self, other: &IpCidrSet) -> IpCidrSet {
+		IpCidrSet {
+			v4: self.v4.union(&other.v4),
+			v6: self.v6.union(&other.v6),
+		}
+	}
+
+	pub fn intersection(&self, other: &IpCidrSet) -> IpCidrSet {
+		IpCidrSet {
+			v4: self.v4.intersection(&other.v4),
+			v6: self.v6.intersection(&other.v6),
+		}
+	}
+
+	pub fn difference(&self, other: &IpCidrSet) -> IpCidrSet {
+		IpCidrSet {
+			v4: self.v4.difference(&other.v4),
+			v6: self.v6.difference(&other.v6),
+		}
+	}
+
+	/// Every address of both families that is not in the set.
+	pub fn complement(&self) -> IpCidrSet {
+		IpCidrSet {
+			v4: self.v4.complement(),
+			v6: self.v6.complement(),
+		}
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.v4.is_empty() && self.v6.is_empty()
+	}
+
+	/// The number of addresses of both families, or `None` when it does not
+	/// fit in a `u128`, as with all of `::/0`.
+	pub fn len(&self) -> Option<u128> {
+		self.v6.len()?.checked_add(self.v4.len() as u128)
+	}
+
+	/// The IPv4 half of the set.
+	pub fn v4(&self) -> &CidrSet {
+		&self.v4
+	}
+
+	/// The IPv6 half of the set.
+	pub fn v6(&self) -> &CidrSet<Cidr6> {
+		&self.v6
+	}
+
+	/// Iterates the set as the minimal list of blocks in `IpCidr` order:
+	/// IPv4 blocks first, then IPv6.
+	pub fn iter(&self) -> impl Iterator<Item = IpCidr> + '_ {
+		let v4 = self.v4.iter().map(IpCidr::V4);
+		let v6 = self.v6.iter().map(IpCidr::V6);
+		v4.chain(v6)
+	}
+
+	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{{")?;
+		for (i, cidr) in self.iter().enumerate() {
+			if i > 0 {
+				write!(f, ", ")?;
+			}
+			write!(f, "{}", cidr)?;
+		}
+		write!(f, "}}")
+	}
+}
+
+impl FromIterator<IpCidr> for IpCidrSet {
+	fn from_iter<T: IntoIterator<Item = IpCidr>>(iter: T) -> Self {
+		let mut set = IpCidrSet::new();
+		set.extend(iter);
+		set
+	}
+}
+
+impl Extend<IpCidr> for IpCidrSet {
+	fn extend<T: IntoIterator<Item = IpCidr>>(&mut self, iter: T) {
+		let mut v4 = Vec::new();
+		let mut v6 = Vec::new();
+
+		for cidr in iter {
+			match cidr {
+				IpCidr::V4(cidr) => v4.push(cidr),
+				IpCidr::V6(cidr) => v6.push(cidr),
+			}
+		}
+
+		self.v4.extend(v4);
+		self.v6.extend(v6);
+	}
+}
+
+impl Debug for IpCidrSet {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+impl Display for IpCidrSet {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::ip_address::IpAddress;
+	use crate::ip_cidr::IpCidr;
+	use crate::ip_cidr_set::IpCidrSet;
+
+	fn cidr(value: &str) -> IpCidr {
+		IpCidr::try_from(value).unwrap()
+	}
+
+	fn addr(value: &str) -> IpAddress {
+		IpAddress::try_from(value).unwrap()
+	}
+
+	fn set(values: &[&str]) -> IpCidrSet {
+		values.iter().map(|s| cidr(s)).collect()
+	}
+
+	#[test]
+	fn insert_test() {
+		let mut fixture = IpCidrSet::new();
+		assert!(fixture.is_empty());
+
+		fixture.insert(cidr("2001:db8:8000::/33"));
+		fixture.insert(cidr("10.0.1.0/24"));
+		fixture.insert(cidr("2001:db8::/33"));
+		fixture.insert(cidr("10.0.0.0/24"));
+
+		assert_eq!(format!("{}", fixture), "{10.0.0.0/23, 2001:db8::/32}");
+		assert!(fixture.contains(&addr("10.0.1.255")));
+		assert!(fixture.contains(&addr("2001:db8:ffff::1")));
+		assert!(!fixture.contains(&addr("::ffff:10.0.0.1")));
+		assert!(fixture.contains(&addr("::ffff:10.0.0.1").to_canonical()));
+		assert_eq!(fixture.v4().ranges().len(), 1);
+		assert_eq!(fixture.v6().ranges().len(), 1);
+	}
+
+	#[test]
+	fn remove_test() {
+		let mut fixture = set(&["10.0.0.0/8", "2001:db8::/32"]);
+
+		fixture.remove(cidr("10.20.0.0/16"));
+		fixture.remove(cidr("2001:db8:1::/48"));
+		fixture.remove(cidr("::/0"));
+		assert!(fixture.v6().is_empty());
+		assert_eq!(fixture.v4().len(), (1 << 24) - (1 << 16));
+		assert!(fixture.contains(&addr("10.19.255.255")));
+		assert!(!fixture.contains(&addr("10.20.0.0")));
+
+		let mut fixture = set(&["10.0.0.0/8", "2001:db8::/32"]);
+		fixture.remove(cidr("0.0.0.0/0"));
+		assert_eq!(format!("{}", fixture), "{2001:db8::/32}");
+	}
+
+	#[test]
+	fn difference_test() {
+		let lhs = set(&["10.0.0.0/23", "2001:db8::/32", "192.168.0.0/24"]);
+		let rhs = set(&["10.0.1.0/24", "2001:db8:8000::/33", "172.16.0.0/12"]);
+
+		let actual = lhs.difference(&rhs);
+		assert_eq!(
+			format!("{}", actual),
+			"{10.0.0.0/24, 192.168.0.0/24, 2001:db8::/33}"
+		);
+
+		let actual = lhs.intersection(&rhs);
+		assert_eq!(format!("{}", actual), "{10.0.1.0/24, 2001:db8:8000::/33}");
+
+		let actual = lhs.union(&rhs);
+		assert_eq!(
+			format!("{:?}", actual),
+			"{10.0.0.0/23, 172.16.0.0/12, 192.168.0.0/24, 2001:db8::/32}"
+		);
+	}
+
+	#[test]
+	fn len_test() {
+		assert_eq!(IpCidrSet::new().len(), Some(0));
+
+		let fixture = set(&["10.0.0.0/24", "10.0.1.0/24", "2001:db8::/120"]);
+		assert_eq!(fixture.len(), Some(512 + 256));
+
+		let fixture = set(&["0.0.0.0/0", "::/1"]);
+		assert_eq!(fixture.len(), Some((1 << 127) + (1 << 32)));
+
+		assert_eq!(set(&["::/0"]).len(), None);
+	}
+
+	#[test]
+	fn complement_test() {
+		let actual = IpCidrSet::new().complement();
+		assert_eq!(format!("{}", actual), "{0.0.0.0/0, ::/0}");
+
+		let actual = set(&["0.0.0.0/1", "::/1"]).complement();
+		assert_eq!(format!("{}", actual), "{128.0.0.0/1, 8000::/1}");
+	}
+}
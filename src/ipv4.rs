@@ -1,8 +1,13 @@
 use std::fmt::{Debug, Display, Formatter};
+use std::net::{IpAddr, Ipv4Addr};
+use std::ops::{Add, BitAnd, BitOr, Not, Sub};
 
 use once_cell::sync::Lazy;
 
 use crate::ipv4_error::Ipv4Error;
+use crate::ipv4_parse_mode::IPv4ParseMode;
+use crate::special_purpose;
+use crate::special_purpose::SpecialPurposeEntry;
 
 struct Factor(u32, u32);
 
@@ -15,21 +20,145 @@ static OFFSET_TABLE: Lazy<[Factor; 4]> = Lazy::new(|| {
 	]
 });
 
-#[derive(Eq, PartialEq, Copy, Clone)]
+/// An IPv4 address.
+///
+/// ```
+/// use cidr_checker::IPv4;
+///
+/// let address = IPv4::try_from("192.168.10.15")?;
+/// assert_eq!(address.to_u32(), 0xc0a8_0a0f);
+/// assert_eq!(address.to_string(), "192.168.10.15");
+/// assert!(address.is_private());
+/// # Ok::<(), cidr_checker::Ipv4Error>(())
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
 pub struct IPv4(u32);
 
 impl IPv4 {
+	/// Parses `value` as `mode` allows. `IPv4::try_from(&str)` is
+	/// `parse_with(value, IPv4ParseMode::Lenient)`.
+	pub fn parse_with(value: &str, mode: IPv4ParseMode) -> Result<IPv4, Ipv4Error> {
+		match mode {
+			IPv4ParseMode::Strict => parse_dotted(value, strict_octet),
+			IPv4ParseMode::Lenient => parse_dotted(value, lenient_octet),
+			IPv4ParseMode::InetAton => parse_inet_aton(value),
+		}
+	}
+
 	pub fn fragment(&self, index: usize) -> Result<u8, Ipv4Error> {
 		if index > 3 {
-			Err(Ipv4Error::IndexOutOfRange)
+			Err(Ipv4Error::IndexOutOfRange(index))
 		} else {
 			let Factor(offset, shift) = OFFSET_TABLE[index];
 			Ok(((self.0 & offset) >> shift) as u8)
 		}
 	}
 
-	pub fn to_u32(&self) -> u32 {
+	pub fn to_u32(self) -> u32 {
+		self.0
+	}
+
+	pub fn checked_add(self, rhs: u32) -> Result<IPv4, Ipv4Error> {
+		self.0
+			.checked_add(rhs)
+			.map(IPv4)
+			.ok_or(Ipv4Error::Overflow { address: self, rhs })
+	}
+
+	pub fn checked_sub(self, rhs: u32) -> Result<IPv4, Ipv4Error> {
 		self.0
+			.checked_sub(rhs)
+			.map(IPv4)
+			.ok_or(Ipv4Error::Underflow { address: self, rhs })
+	}
+
+	pub fn saturating_add(self, rhs: u32) -> IPv4 {
+		IPv4(self.0.saturating_add(rhs))
+	}
+
+	pub fn saturating_sub(self, rhs: u32) -> IPv4 {
+		IPv4(self.0.saturating_sub(rhs))
+	}
+
+	pub fn wrapping_add(self, rhs: u32) -> IPv4 {
+		IPv4(self.0.wrapping_add(rhs))
+	}
+
+	pub fn wrapping_sub(self, rhs: u32) -> IPv4 {
+		IPv4(self.0.wrapping_sub(rhs))
+	}
+
+	pub fn successor(self) -> Result<IPv4, Ipv4Error> {
+		self.checked_add(1)
+	}
+
+	pub fn predecessor(self) -> Result<IPv4, Ipv4Error> {
+		self.checked_sub(1)
+	}
+
+	pub fn is_unspecified(&self) -> bool {
+		self.0 == 0
+	}
+
+	/// RFC 1918 private-use space.
+	pub fn is_private(&self) -> bool {
+		self.within(0x0a00_0000, 8) || self.within(0xac10_0000, 12) || self.within(0xc0a8_0000, 16)
+	}
+
+	pub fn is_loopback(&self) -> bool {
+		self.within(0x7f00_0000, 8)
+	}
+
+	pub fn is_link_local(&self) -> bool {
+		self.within(0xa9fe_0000, 16)
+	}
+
+	/// RFC 6598 shared address space (carrier-grade NAT).
+	pub fn is_shared(&self) -> bool {
+		self.within(0x6440_0000, 10)
+	}
+
+	/// TEST-NET-1, TEST-NET-2 and TEST-NET-3 (RFC 5737).
+	pub fn is_documentation(&self) -> bool {
+		self.within(0xc000_0200, 24) || self.within(0xc633_6400, 24) || self.within(0xcb00_7100, 24)
+	}
+
+	pub fn is_benchmarking(&self) -> bool {
+		self.within(0xc612_0000, 15)
+	}
+
+	pub fn is_multicast(&self) -> bool {
+		self.within(0xe000_0000, 4)
+	}
+
+	/// 240.0.0.0/4, except the limited broadcast address.
+	pub fn is_reserved(&self) -> bool {
+		self.within(0xf000_0000, 4) && !self.is_broadcast()
+	}
+
+	pub fn is_broadcast(&self) -> bool {
+		self.0 == u32::MAX
+	}
+
+	/// Decided by the most specific special-purpose entry containing the
+	/// address; addresses outside the registry are global.
+	pub fn is_global(&self) -> bool {
+		self.special_purpose()
+			.into_iter()
+			.max_by_key(|entry| entry.cidr().subnet_mask().cidr())
+			.is_none_or(|entry| entry.global())
+	}
+
+	/// Every special-purpose registry entry containing the address.
+	pub fn special_purpose(&self) -> Vec<&'static SpecialPurposeEntry> {
+		special_purpose::registry()
+			.iter()
+			.filter(|entry| entry.cidr().contains(self))
+			.collect()
+	}
+
+	fn within(&self, network: u32, prefix: u8) -> bool {
+		(self.0 ^ network) >> (32 - prefix) == 0
 	}
 
 	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -49,12 +178,41 @@ impl From<u32> for IPv4 {
 	}
 }
 
+impl From<Ipv4Addr> for IPv4 {
+	fn from(value: Ipv4Addr) -> Self {
+		IPv4(u32::from(value))
+	}
+}
+
+impl From<IPv4> for Ipv4Addr {
+	fn from(value: IPv4) -> Self {
+		Ipv4Addr::from(value.0)
+	}
+}
+
+impl From<IPv4> for IpAddr {
+	fn from(value: IPv4) -> Self {
+		IpAddr::V4(value.into())
+	}
+}
+
+impl TryFrom<IpAddr> for IPv4 {
+	type Error = Ipv4Error;
+
+	fn try_from(value: IpAddr) -> Result<Self, Self::Error> {
+		match value {
+			IpAddr::V4(addr) => Ok(addr.into()),
+			IpAddr::V6(addr) => Err(Ipv4Error::Ipv6Address(addr.to_string())),
+		}
+	}
+}
+
 impl TryFrom<&[u8]> for IPv4 {
 	type Error = Ipv4Error;
 
 	fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
 		if value.len() != 4 {
-			Err(Ipv4Error::InvalidArrayLength)
+			Err(Ipv4Error::InvalidArrayLength(value.len()))
 		} else {
 			let mut result = 0u32;
 			for i in 0..value.len() {
@@ -69,31 +227,176 @@ impl TryFrom<&[u8]> for IPv4 {
 	}
 }
 
+fn invalid_value(input: &str, position: usize) -> Ipv4Error {
+	Ipv4Error::InvalidValue {
+		input: input.to_string(),
+		position,
+	}
+}
+
+fn lenient_octet(input: &str, octet: &str, position: usize) -> Result<u8, Ipv4Error> {
+	octet
+		.parse::<u8>()
+		.map_err(|_| invalid_value(input, position))
+}
+
+fn strict_octet(input: &str, octet: &str, position: usize) -> Result<u8, Ipv4Error> {
+	if octet.is_empty() || !octet.bytes().all(|b| b.is_ascii_digit()) {
+		Err(invalid_value(input, position))
+	} else if octet.len() > 1 && octet.starts_with('0') {
+		Err(Ipv4Error::LeadingZero {
+			input: input.to_string(),
+			position,
+		})
+	} else {
+		lenient_octet(input, octet, position)
+	}
+}
+
+/// Four dot-separated octets, each read by `octet`.
+fn parse_dotted<F>(value: &str, octet: F) -> Result<IPv4, Ipv4Error>
+where
+	F: Fn(&str, &str, usize) -> Result<u8, Ipv4Error>,
+{
+	let mut cnt = 0;
+	let mut accum: u32 = 0;
+
+	for elem in value.split(".") {
+		if cnt >= 4 {
+			return Err(Ipv4Error::InvalidFormat(value.to_string()));
+		}
+
+		let Factor(_, shift) = OFFSET_TABLE[cnt];
+
+		let tmp = octet(value, elem, cnt + 1)? as u32;
+		accum |= tmp << shift;
+
+		cnt += 1;
+	}
+
+	if cnt != 4 {
+		Err(Ipv4Error::InvalidFormat(value.to_string()))
+	} else {
+		Ok(IPv4(accum))
+	}
+}
+
+/// A number in C notation: `0x` for hexadecimal, a leading `0` for octal,
+/// decimal otherwise. Signs and whitespace are not accepted.
+fn aton_number(part: &str) -> Option<u64> {
+	let (digits, radix) =
+		if let Some(hex) = part.strip_prefix("0x").or_else(|| part.strip_prefix("0X")) {
+			(hex, 16)
+		} else if part.len() > 1 && part.starts_with('0') {
+			(&part[1..], 8)
+		} else {
+			(part, 10)
+		};
+
+	if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+		None
+	} else {
+		u64::from_str_radix(digits, radix).ok()
+	}
+}
+
+/// One to four parts. All but the last are single bytes; the last fills the
+/// bytes that remain, so `127.1` is `127.0.0.1`.
+fn parse_inet_aton(value: &str) -> Result<IPv4, Ipv4Error> {
+	let parts: Vec<_> = value.split('.').collect();
+
+	if parts.len() > 4 {
+		return Err(Ipv4Error::InvalidFormat(value.to_string()));
+	}
+
+	let mut accum: u32 = 0;
+
+	for (index, part) in parts.iter().enumerate() {
+		let position = index + 1;
+		let number = aton_number(part).ok_or_else(|| invalid_value(value, position))?;
+
+		let (max, shift) = if position == parts.len() {
+			(u32::MAX >> (8 * index), 0)
+		} else {
+			(0xff, 24 - 8 * index as u32)
+		};
+
+		if number > max as u64 {
+			return Err(Ipv4Error::ValueOutOfRange {
+				input: value.to_string(),
+				position,
+				max,
+			});
+		}
+
+		accum |= (number as u32) << shift;
+	}
+
+	Ok(IPv4(accum))
+}
+
 impl TryFrom<&str> for IPv4 {
 	type Error = Ipv4Error;
 
 	fn try_from(value: &str) -> Result<Self, Self::Error> {
-		let mut cnt = 0;
-		let mut accum: u32 = 0;
+		IPv4::parse_with(value, IPv4ParseMode::Lenient)
+	}
+}
 
-		for elem in value.split(".") {
-			if cnt >= 4 {
-				return Err(Ipv4Error::InvalidFormat);
-			}
+/// Panics past 255.255.255.255; use `checked_add` to handle overflow.
+impl Add<u32> for IPv4 {
+	type Output = IPv4;
 
-			let Factor(_, shift) = OFFSET_TABLE[cnt];
+	fn add(self, rhs: u32) -> Self::Output {
+		match self.checked_add(rhs) {
+			Ok(address) => address,
+			Err(_) => panic!("IPv4 address overflow: {} + {}", self, rhs),
+		}
+	}
+}
 
-			let tmp = elem.parse::<u8>().map_err(|_| Ipv4Error::InvalidValue)? as u32;
-			accum |= tmp << shift;
+/// Panics below 0.0.0.0; use `checked_sub` to handle underflow.
+impl Sub<u32> for IPv4 {
+	type Output = IPv4;
 
-			cnt += 1;
+	fn sub(self, rhs: u32) -> Self::Output {
+		match self.checked_sub(rhs) {
+			Ok(address) => address,
+			Err(_) => panic!("IPv4 address underflow: {} - {}", self, rhs),
 		}
+	}
+}
 
-		if cnt != 4 {
-			Err(Ipv4Error::InvalidFormat)
-		} else {
-			Ok(IPv4(accum))
-		}
+/// The signed distance between two addresses.
+impl Sub<IPv4> for IPv4 {
+	type Output = i64;
+
+	fn sub(self, rhs: IPv4) -> Self::Output {
+		self.0 as i64 - rhs.0 as i64
+	}
+}
+
+impl BitAnd for IPv4 {
+	type Output = IPv4;
+
+	fn bitand(self, rhs: IPv4) -> Self::Output {
+		IPv4(self.0 & rhs.0)
+	}
+}
+
+impl BitOr for IPv4 {
+	type Output = IPv4;
+
+	fn bitor(self, rhs: IPv4) -> Self::Output {
+		IPv4(self.0 | rhs.0)
+	}
+}
+
+impl Not for IPv4 {
+	type Output = IPv4;
+
+	fn not(self) -> Self::Output {
+		IPv4(!self.0)
 	}
 }
 
@@ -111,29 +414,29 @@ impl Display for IPv4 {
 
 #[cfg(test)]
 mod tests {
+	use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
 	use crate::ipv4::IPv4;
 	use crate::ipv4_error::Ipv4Error;
+	use crate::ipv4_parse_mode::IPv4ParseMode;
 
 	fn assert(actual: IPv4, expected: &[u8; 4]) {
-		for i in 0..4usize {
-			assert_eq!(actual.fragment(i).unwrap(), expected[i])
+		for (i, expected) in expected.iter().enumerate() {
+			assert_eq!(actual.fragment(i).unwrap(), *expected)
 		}
 	}
 
 	fn assert_err<T>(actual: Result<T, Ipv4Error>, expected: Ipv4Error) {
-		fn to_ordinal(e: Ipv4Error) -> usize {
-			match e {
-				Ipv4Error::IndexOutOfRange => 1,
-				Ipv4Error::InvalidFormat => 2,
-				Ipv4Error::InvalidArrayLength => 3,
-				Ipv4Error::InvalidValue => 4,
-			}
+		match actual {
+			Ok(_) => unreachable!(),
+			Err(err) => assert_eq!(err, expected),
 		}
+	}
 
-		if let Err(err) = actual {
-			assert_eq!(to_ordinal(err), to_ordinal(expected));
-		} else {
-			unreachable!()
+	fn invalid_value(input: &str, position: usize) -> Ipv4Error {
+		Ipv4Error::InvalidValue {
+			input: input.to_string(),
+			position,
 		}
 	}
 
@@ -154,15 +457,153 @@ mod tests {
 		let fixture = IPv4::try_from("192.168.10.102").unwrap();
 		assert(fixture, &[192u8, 168u8, 10u8, 102u8]);
 
-		assert_err(IPv4::try_from("192.168.10.256"), Ipv4Error::InvalidValue);
-		assert_err(IPv4::try_from("192.168.256.10"), Ipv4Error::InvalidValue);
-		assert_err(IPv4::try_from("192.256.10.102"), Ipv4Error::InvalidValue);
-		assert_err(IPv4::try_from("256.168.10.102"), Ipv4Error::InvalidValue);
+		assert_err(
+			IPv4::try_from("192.168.10.256"),
+			invalid_value("192.168.10.256", 4),
+		);
+		assert_err(
+			IPv4::try_from("192.168.256.10"),
+			invalid_value("192.168.256.10", 3),
+		);
+		assert_err(
+			IPv4::try_from("192.256.10.102"),
+			invalid_value("192.256.10.102", 2),
+		);
+		assert_err(
+			IPv4::try_from("256.168.10.102"),
+			invalid_value("256.168.10.102", 1),
+		);
 
-		assert_err(IPv4::try_from("192.168.10"), Ipv4Error::InvalidFormat);
+		assert_err(
+			IPv4::try_from("192.168.10"),
+			Ipv4Error::InvalidFormat("192.168.10".to_string()),
+		);
 		assert_err(
 			IPv4::try_from("192.168.10.10.102"),
-			Ipv4Error::InvalidFormat,
+			Ipv4Error::InvalidFormat("192.168.10.10.102".to_string()),
+		);
+	}
+
+	#[test]
+	fn parse_with_strict_test() {
+		let fixture = IPv4::parse_with("192.168.0.10", IPv4ParseMode::Strict).unwrap();
+		assert(fixture, &[192u8, 168u8, 0u8, 10u8]);
+
+		let fixture = IPv4::parse_with("0.0.0.0", IPv4ParseMode::Strict).unwrap();
+		assert(fixture, &[0u8, 0u8, 0u8, 0u8]);
+
+		assert_err(
+			IPv4::parse_with("010.001.000.001", IPv4ParseMode::Strict),
+			Ipv4Error::LeadingZero {
+				input: "010.001.000.001".to_string(),
+				position: 1,
+			},
+		);
+		assert_err(
+			IPv4::parse_with("10.0.0.00", IPv4ParseMode::Strict),
+			Ipv4Error::LeadingZero {
+				input: "10.0.0.00".to_string(),
+				position: 4,
+			},
+		);
+
+		for input in [
+			" 10.0.0.1",
+			"10.0.0.1 ",
+			"10. 0.0.1",
+			"+10.0.0.1",
+			"10..0.1",
+		] {
+			assert!(IPv4::parse_with(input, IPv4ParseMode::Strict).is_err());
+		}
+
+		assert_err(
+			IPv4::parse_with("10.0.0.256", IPv4ParseMode::Strict),
+			invalid_value("10.0.0.256", 4),
+		);
+		assert_err(
+			IPv4::parse_with("10.0.1", IPv4ParseMode::Strict),
+			Ipv4Error::InvalidFormat("10.0.1".to_string()),
+		);
+	}
+
+	#[test]
+	fn parse_with_lenient_test() {
+		let fixture = IPv4::parse_with("010.001.000.001", IPv4ParseMode::Lenient).unwrap();
+		assert(fixture, &[10u8, 1u8, 0u8, 1u8]);
+
+		let fixture = IPv4::parse_with("+10.0.0.1", IPv4ParseMode::Lenient).unwrap();
+		assert(fixture, &[10u8, 0u8, 0u8, 1u8]);
+
+		assert_err(
+			IPv4::parse_with(" 10.0.0.1", IPv4ParseMode::Lenient),
+			invalid_value(" 10.0.0.1", 1),
+		);
+		assert_eq!(IPv4ParseMode::default(), IPv4ParseMode::Lenient);
+	}
+
+	#[test]
+	fn parse_with_inet_aton_test() {
+		for (input, expected) in [
+			("127.0.0.1", [127u8, 0u8, 0u8, 1u8]),
+			("127.1", [127u8, 0u8, 0u8, 1u8]),
+			("0x7f.1", [127u8, 0u8, 0u8, 1u8]),
+			("0177.0.0.01", [127u8, 0u8, 0u8, 1u8]),
+			("2130706433", [127u8, 0u8, 0u8, 1u8]),
+			("0x7F000001", [127u8, 0u8, 0u8, 1u8]),
+			("10.1.65535", [10u8, 1u8, 255u8, 255u8]),
+			("10.0xffffff", [10u8, 255u8, 255u8, 255u8]),
+			("010.001.000.001", [8u8, 1u8, 0u8, 1u8]),
+			("0", [0u8, 0u8, 0u8, 0u8]),
+			("4294967295", [255u8, 255u8, 255u8, 255u8]),
+		] {
+			let actual = IPv4::parse_with(input, IPv4ParseMode::InetAton).unwrap();
+			assert(actual, &expected);
+		}
+
+		assert_err(
+			IPv4::parse_with("4294967296", IPv4ParseMode::InetAton),
+			Ipv4Error::ValueOutOfRange {
+				input: "4294967296".to_string(),
+				position: 1,
+				max: u32::MAX,
+			},
+		);
+		assert_err(
+			IPv4::parse_with("10.16777216", IPv4ParseMode::InetAton),
+			Ipv4Error::ValueOutOfRange {
+				input: "10.16777216".to_string(),
+				position: 2,
+				max: 0xff_ffff,
+			},
+		);
+		assert_err(
+			IPv4::parse_with("256.1", IPv4ParseMode::InetAton),
+			Ipv4Error::ValueOutOfRange {
+				input: "256.1".to_string(),
+				position: 1,
+				max: 0xff,
+			},
+		);
+		assert_err(
+			IPv4::parse_with("08.0.0.1", IPv4ParseMode::InetAton),
+			invalid_value("08.0.0.1", 1),
+		);
+		assert_err(
+			IPv4::parse_with("10.0x", IPv4ParseMode::InetAton),
+			invalid_value("10.0x", 2),
+		);
+		assert_err(
+			IPv4::parse_with("10..1", IPv4ParseMode::InetAton),
+			invalid_value("10..1", 2),
+		);
+		assert_err(
+			IPv4::parse_with("", IPv4ParseMode::InetAton),
+			invalid_value("", 1),
+		);
+		assert_err(
+			IPv4::parse_with("1.2.3.4.5", IPv4ParseMode::InetAton),
+			Ipv4Error::InvalidFormat("1.2.3.4.5".to_string()),
 		);
 	}
 
@@ -173,11 +614,11 @@ mod tests {
 
 		assert_err(
 			IPv4::try_from(&[192u8, 168u8, 10u8][..]),
-			Ipv4Error::InvalidArrayLength,
+			Ipv4Error::InvalidArrayLength(3),
 		);
 		assert_err(
 			IPv4::try_from(&[192u8, 168u8, 10u8, 102u8, 0u8][..]),
-			Ipv4Error::InvalidArrayLength,
+			Ipv4Error::InvalidArrayLength(5),
 		);
 	}
 
@@ -200,7 +641,7 @@ mod tests {
 		assert_eq!(fixture.fragment(2).unwrap(), 10u8);
 		assert_eq!(fixture.fragment(3).unwrap(), 102u8);
 
-		assert_err(fixture.fragment(4), Ipv4Error::IndexOutOfRange);
+		assert_err(fixture.fragment(4), Ipv4Error::IndexOutOfRange(4));
 	}
 
 	#[test]
@@ -226,4 +667,207 @@ mod tests {
 		assert!(!(a == b));
 		assert!(a != b);
 	}
+
+	#[test]
+	fn ord_test() {
+		let a = IPv4::try_from("10.0.0.1").unwrap();
+		let b = IPv4::try_from("9.255.255.255").unwrap();
+
+		assert!(b < a);
+		assert_eq!(a.max(b), a);
+	}
+
+	#[test]
+	fn checked_test() {
+		let fixture = IPv4::try_from("10.0.0.255").unwrap();
+
+		assert_eq!(
+			fixture.checked_add(1).unwrap(),
+			IPv4::try_from("10.0.1.0").unwrap()
+		);
+		assert_eq!(
+			fixture.checked_sub(256).unwrap(),
+			IPv4::try_from("9.255.255.255").unwrap()
+		);
+
+		assert_err(
+			IPv4::from(u32::MAX).checked_add(1),
+			Ipv4Error::Overflow {
+				address: IPv4::from(u32::MAX),
+				rhs: 1,
+			},
+		);
+		assert_err(
+			IPv4::from(0).checked_sub(1),
+			Ipv4Error::Underflow {
+				address: IPv4::from(0),
+				rhs: 1,
+			},
+		);
+	}
+
+	#[test]
+	fn saturating_test() {
+		assert_eq!(
+			IPv4::from(u32::MAX - 1).saturating_add(5),
+			IPv4::from(u32::MAX)
+		);
+		assert_eq!(IPv4::from(3).saturating_sub(5), IPv4::from(0));
+		assert_eq!(IPv4::from(3).saturating_add(5), IPv4::from(8));
+	}
+
+	#[test]
+	fn wrapping_test() {
+		assert_eq!(IPv4::from(u32::MAX).wrapping_add(2), IPv4::from(1));
+		assert_eq!(IPv4::from(0).wrapping_sub(1), IPv4::from(u32::MAX));
+	}
+
+	#[test]
+	fn successor_and_predecessor_test() {
+		let fixture = IPv4::try_from("192.168.10.102").unwrap();
+
+		assert_eq!(
+			fixture.successor().unwrap(),
+			IPv4::try_from("192.168.10.103").unwrap()
+		);
+		assert_eq!(
+			fixture.predecessor().unwrap(),
+			IPv4::try_from("192.168.10.101").unwrap()
+		);
+
+		assert!(matches!(
+			IPv4::from(u32::MAX).successor(),
+			Err(Ipv4Error::Overflow { .. })
+		));
+		assert!(matches!(
+			IPv4::from(0).predecessor(),
+			Err(Ipv4Error::Underflow { .. })
+		));
+	}
+
+	#[test]
+	fn add_and_sub_test() {
+		let fixture = IPv4::try_from("192.168.10.102").unwrap();
+
+		assert_eq!(fixture + 154, IPv4::try_from("192.168.11.0").unwrap());
+		assert_eq!(fixture - 103, IPv4::try_from("192.168.9.255").unwrap());
+		assert_eq!(fixture - IPv4::try_from("192.168.10.100").unwrap(), 2);
+		assert_eq!(IPv4::from(0) - IPv4::from(u32::MAX), -(u32::MAX as i64));
+	}
+
+	#[test]
+	#[should_panic]
+	fn add_overflow_test() {
+		let _ = IPv4::from(u32::MAX) + 1;
+	}
+
+	#[test]
+	#[should_panic]
+	fn sub_underflow_test() {
+		let _ = IPv4::from(0) - 1;
+	}
+
+	#[test]
+	fn bit_test() {
+		let address = IPv4::try_from("192.168.10.102").unwrap();
+		let mask = IPv4::try_from("255.255.255.0").unwrap();
+
+		assert_eq!(address & mask, IPv4::try_from("192.168.10.0").unwrap());
+		assert_eq!(address | !mask, IPv4::try_from("192.168.10.255").unwrap());
+		assert_eq!(!mask, IPv4::try_from("0.0.0.255").unwrap());
+	}
+
+	fn addr(value: &str) -> IPv4 {
+		IPv4::try_from(value).unwrap()
+	}
+
+	#[test]
+	fn classification_test() {
+		assert!(addr("0.0.0.0").is_unspecified());
+		assert!(!addr("0.0.0.1").is_unspecified());
+
+		assert!(addr("10.1.2.3").is_private());
+		assert!(addr("172.31.255.255").is_private());
+		assert!(!addr("172.32.0.0").is_private());
+		assert!(addr("192.168.0.1").is_private());
+		assert!(!addr("100.64.0.1").is_private());
+
+		assert!(addr("127.0.0.1").is_loopback());
+		assert!(addr("169.254.1.1").is_link_local());
+		assert!(addr("100.127.255.255").is_shared());
+		assert!(!addr("100.128.0.0").is_shared());
+
+		assert!(addr("192.0.2.1").is_documentation());
+		assert!(addr("198.51.100.1").is_documentation());
+		assert!(addr("203.0.113.1").is_documentation());
+		assert!(!addr("203.0.114.1").is_documentation());
+
+		assert!(addr("198.19.0.1").is_benchmarking());
+		assert!(!addr("198.20.0.1").is_benchmarking());
+
+		assert!(addr("224.0.0.1").is_multicast());
+		assert!(addr("239.255.255.255").is_multicast());
+		assert!(!addr("240.0.0.0").is_multicast());
+
+		assert!(addr("240.0.0.0").is_reserved());
+		assert!(!addr("255.255.255.255").is_reserved());
+		assert!(addr("255.255.255.255").is_broadcast());
+	}
+
+	#[test]
+	fn is_global_test() {
+		assert!(addr("8.8.8.8").is_global());
+		assert!(addr("192.0.0.9").is_global());
+		assert!(addr("192.31.196.1").is_global());
+
+		assert!(!addr("10.0.0.1").is_global());
+		assert!(!addr("100.64.0.1").is_global());
+		assert!(!addr("192.0.0.1").is_global());
+		assert!(!addr("192.0.0.100").is_global());
+		assert!(!addr("0.0.0.0").is_global());
+		assert!(!addr("255.255.255.255").is_global());
+	}
+
+	#[test]
+	fn special_purpose_test() {
+		let actual: Vec<_> = addr("0.0.0.0")
+			.special_purpose()
+			.iter()
+			.map(|e| format!("{}", e.cidr()))
+			.collect();
+		assert_eq!(actual, ["0.0.0.0/8", "0.0.0.0/32"]);
+
+		let actual = addr("172.16.5.4").special_purpose();
+		assert_eq!(actual.len(), 1);
+		assert_eq!(actual[0].rfc(), "RFC 1918");
+
+		assert!(addr("8.8.8.8").special_purpose().is_empty());
+	}
+
+	#[test]
+	fn std_net_test() {
+		let std = Ipv4Addr::new(192, 168, 10, 102);
+		let fixture = IPv4::from(std);
+
+		assert_eq!(fixture, IPv4::from(0xC0_A8_0A_66));
+		assert_eq!(Ipv4Addr::from(fixture), std);
+		assert_eq!(IpAddr::from(fixture), IpAddr::V4(std));
+	}
+
+	#[test]
+	fn try_from_ip_addr_test() {
+		let actual = IPv4::try_from(IpAddr::V4(Ipv4Addr::LOCALHOST)).unwrap();
+		assert!(actual.is_loopback());
+
+		assert_err(
+			IPv4::try_from(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+			Ipv4Error::Ipv6Address("::1".to_string()),
+		);
+
+		let mapped = Ipv4Addr::new(192, 168, 10, 102).to_ipv6_mapped();
+		assert_err(
+			IPv4::try_from(IpAddr::V6(mapped)),
+			Ipv4Error::Ipv6Address("::ffff:192.168.10.102".to_string()),
+		);
+	}
 }
@@ -1,68 +1,220 @@
-use std::fmt::{Debug, Display, Formatter};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
-pub enum Ipv4Error {
-	IndexOutOfRange,
-	InvalidFormat,
-	InvalidArrayLength,
-	InvalidValue,
-}
-
-impl Ipv4Error {
-	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		let scr = match self {
-			Ipv4Error::IndexOutOfRange => "IPv4Error::IndexOutOfRange",
-			Ipv4Error::InvalidFormat => "IPv4Error::InvalidFormat",
-			Ipv4Error::InvalidArrayLength => "IPv4Error::InvalidArrayLength",
-			Ipv4Error::InvalidValue => "IPv4Error::InvalidValue",
-		};
+use crate::ipv4::IPv4;
 
-		write!(f, "{}", scr)
-	}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ipv4Error {
+	IndexOutOfRange(usize),
+	InvalidFormat(String),
+	InvalidArrayLength(usize),
+	/// `position` is the 1-based index of the offending octet in `input`.
+	InvalidValue {
+		input: String,
+		position: usize,
+	},
+	/// A strictly parsed octet such as `010`, which other parsers read as
+	/// octal.
+	LeadingZero {
+		input: String,
+		position: usize,
+	},
+	/// An `inet_aton` part that parses but does not fit the bytes left for
+	/// it, such as the `256` in `1.2.3.256` or `16777216` in `1.16777216`.
+	ValueOutOfRange {
+		input: String,
+		position: usize,
+		max: u32,
+	},
+	Overflow {
+		address: IPv4,
+		rhs: u32,
+	},
+	Underflow {
+		address: IPv4,
+		rhs: u32,
+	},
+	Ipv6Address(String),
 }
 
-impl Debug for Ipv4Error {
+impl Display for Ipv4Error {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		self.format(f)
+		match self {
+			Ipv4Error::IndexOutOfRange(index) => {
+				write!(f, "octet index {} is out of range (expected 0 to 3)", index)
+			}
+			Ipv4Error::InvalidFormat(input) => write!(
+				f,
+				"\"{}\" is not an IPv4 address: expected four dot-separated octets",
+				input
+			),
+			Ipv4Error::InvalidArrayLength(len) => {
+				write!(f, "an IPv4 address needs 4 bytes, got {}", len)
+			}
+			Ipv4Error::InvalidValue { input, position } => write!(
+				f,
+				"octet {} of \"{}\"{} is not a number from 0 to 255",
+				position,
+				input,
+				quoted_part(input, *position)
+			),
+			Ipv4Error::LeadingZero { input, position } => write!(
+				f,
+				"octet {} of \"{}\"{} has a leading zero, which may be read as octal",
+				position,
+				input,
+				quoted_part(input, *position)
+			),
+			Ipv4Error::ValueOutOfRange {
+				input,
+				position,
+				max,
+			} => write!(
+				f,
+				"part {} of \"{}\"{} is greater than {}",
+				position,
+				input,
+				quoted_part(input, *position),
+				max
+			),
+			Ipv4Error::Overflow { address, rhs } => {
+				write!(f, "{} + {} overflows past 255.255.255.255", address, rhs)
+			}
+			Ipv4Error::Underflow { address, rhs } => {
+				write!(f, "{} - {} underflows past 0.0.0.0", address, rhs)
+			}
+			Ipv4Error::Ipv6Address(input) => write!(f, "{} is an IPv6 address, not IPv4", input),
+		}
 	}
 }
 
-impl Display for Ipv4Error {
-	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		self.format(f)
-	}
+impl Error for Ipv4Error {}
+
+/// ` ("<part>")` for the 1-based `position` in `input`, or nothing when the
+/// error was built with a position that is not in `input`.
+fn quoted_part(input: &str, position: usize) -> String {
+	position
+		.checked_sub(1)
+		.and_then(|index| input.split('.').nth(index))
+		.map(|part| format!(" (\"{}\")", part))
+		.unwrap_or_default()
 }
 
 #[cfg(test)]
 mod tests {
+	use crate::ipv4::IPv4;
 	use crate::ipv4_error::Ipv4Error;
 
 	#[test]
-	pub fn debug_test() {
-		let actual = format!("{:?}", Ipv4Error::IndexOutOfRange);
-		assert_eq!(actual, "IPv4Error::IndexOutOfRange");
+	pub fn display_test() {
+		let actual = format!("{}", Ipv4Error::IndexOutOfRange(4));
+		assert_eq!(actual, "octet index 4 is out of range (expected 0 to 3)");
 
-		let actual = format!("{:?}", Ipv4Error::InvalidFormat);
-		assert_eq!(actual, "IPv4Error::InvalidFormat");
+		let actual = format!("{}", Ipv4Error::InvalidFormat("10.0.0".to_string()));
+		assert_eq!(
+			actual,
+			"\"10.0.0\" is not an IPv4 address: expected four dot-separated octets"
+		);
 
-		let actual = format!("{:?}", Ipv4Error::InvalidArrayLength);
-		assert_eq!(actual, "IPv4Error::InvalidArrayLength");
+		let actual = format!("{}", Ipv4Error::InvalidArrayLength(5));
+		assert_eq!(actual, "an IPv4 address needs 4 bytes, got 5");
 
-		let actual = format!("{:?}", Ipv4Error::InvalidValue);
-		assert_eq!(actual, "IPv4Error::InvalidValue")
-	}
+		let actual = format!(
+			"{}",
+			Ipv4Error::InvalidValue {
+				input: "10.0.300.1".to_string(),
+				position: 3
+			}
+		);
+		assert_eq!(
+			actual,
+			"octet 3 of \"10.0.300.1\" (\"300\") is not a number from 0 to 255"
+		);
 
-	#[test]
-	pub fn display_test() {
-		let actual = format!("{:}", Ipv4Error::IndexOutOfRange);
-		assert_eq!(actual, "IPv4Error::IndexOutOfRange");
+		let actual = format!(
+			"{}",
+			Ipv4Error::LeadingZero {
+				input: "10.0.010.1".to_string(),
+				position: 3
+			}
+		);
+		assert_eq!(
+			actual,
+			"octet 3 of \"10.0.010.1\" (\"010\") has a leading zero, which may be read as octal"
+		);
+
+		let actual = format!(
+			"{}",
+			Ipv4Error::ValueOutOfRange {
+				input: "10.16777216".to_string(),
+				position: 2,
+				max: 0xff_ffff
+			}
+		);
+		assert_eq!(
+			actual,
+			"part 2 of \"10.16777216\" (\"16777216\") is greater than 16777215"
+		);
+
+		let actual = format!(
+			"{}",
+			Ipv4Error::InvalidValue {
+				input: "10.0.300.1".to_string(),
+				position: 0
+			}
+		);
+		assert_eq!(
+			actual,
+			"octet 0 of \"10.0.300.1\" is not a number from 0 to 255"
+		);
 
-		let actual = format!("{:}", Ipv4Error::InvalidFormat);
-		assert_eq!(actual, "IPv4Error::InvalidFormat");
+		let actual = format!(
+			"{}",
+			Ipv4Error::LeadingZero {
+				input: "10.0.010.1".to_string(),
+				position: 5
+			}
+		);
+		assert_eq!(
+			actual,
+			"octet 5 of \"10.0.010.1\" has a leading zero, which may be read as octal"
+		);
 
-		let actual = format!("{:}", Ipv4Error::InvalidArrayLength);
-		assert_eq!(actual, "IPv4Error::InvalidArrayLength");
+		let actual = format!(
+			"{}",
+			Ipv4Error::ValueOutOfRange {
+				input: "10.16777216".to_string(),
+				position: 0,
+				max: 0xff_ffff
+			}
+		);
+		assert_eq!(actual, "part 0 of \"10.16777216\" is greater than 16777215");
 
-		let actual = format!("{:}", Ipv4Error::InvalidValue);
-		assert_eq!(actual, "IPv4Error::InvalidValue")
+		let actual = format!(
+			"{}",
+			Ipv4Error::Overflow {
+				address: IPv4::from(0xffff_fffeu32),
+				rhs: 2
+			}
+		);
+		assert_eq!(actual, "255.255.255.254 + 2 overflows past 255.255.255.255");
+
+		let actual = format!(
+			"{}",
+			Ipv4Error::Underflow {
+				address: IPv4::from(1u32),
+				rhs: 2
+			}
+		);
+		assert_eq!(actual, "0.0.0.1 - 2 underflows past 0.0.0.0");
+
+		let actual = format!("{}", Ipv4Error::Ipv6Address("::1".to_string()));
+		assert_eq!(actual, "::1 is an IPv6 address, not IPv4");
+	}
+
+	#[test]
+	pub fn error_test() {
+		let fixture: Box<dyn std::error::Error> = Box::new(Ipv4Error::IndexOutOfRange(4));
+		assert!(fixture.source().is_none());
 	}
 }
@@ -0,0 +1,179 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::cidr;
+use crate::cidr::Cidr;
+use crate::cidr_error::CidrError;
+use crate::ipv4::IPv4;
+use crate::ipv4_mask::IPv4Mask;
+use crate::ipv4_parse_mode::IPv4ParseMode;
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub struct IPv4Interface {
+	address: IPv4,
+	mask: IPv4Mask,
+}
+
+impl IPv4Interface {
+	pub fn new(address: IPv4, mask: IPv4Mask) -> IPv4Interface {
+		IPv4Interface { address, mask }
+	}
+
+	/// Parses `value` like `try_from(&str)`, reading the address and the
+	/// prefix as `mode` allows.
+	pub fn parse_with(value: &str, mode: IPv4ParseMode) -> Result<IPv4Interface, CidrError> {
+		let (address, mask) = cidr::parse_parts(value, mode)?;
+		Ok(IPv4Interface::new(address, mask))
+	}
+
+	pub fn address(&self) -> &IPv4 {
+		&self.address
+	}
+
+	pub fn subnet_mask(&self) -> &IPv4Mask {
+		&self.mask
+	}
+
+	pub fn network(&self) -> Cidr {
+		Cidr::from_host(self.address, self.mask)
+	}
+
+	pub fn broadcast(&self) -> IPv4 {
+		self.mask.broadcast_address(&self.address)
+	}
+
+	pub fn is_network_address(&self) -> bool {
+		self.mask.network_address(&self.address) == self.address
+	}
+
+	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}{}", self.address, self.mask)
+	}
+}
+
+impl TryFrom<&str> for IPv4Interface {
+	type Error = CidrError;
+
+	/// Accepts the same notation as `Cidr::try_from(&str)`, but keeps host bits.
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		IPv4Interface::parse_with(value, IPv4ParseMode::Lenient)
+	}
+}
+
+impl FromStr for IPv4Interface {
+	type Err = CidrError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		IPv4Interface::try_from(s)
+	}
+}
+
+impl Debug for IPv4Interface {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+impl Display for IPv4Interface {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::cidr_error::CidrError;
+	use crate::ipv4::IPv4;
+	use crate::ipv4_error::Ipv4Error;
+	use crate::ipv4_interface::IPv4Interface;
+	use crate::ipv4_mask::IPv4Mask;
+	use crate::ipv4_mask_error::IPv4MaskError;
+	use crate::ipv4_parse_mode::IPv4ParseMode;
+
+	fn fixture() -> IPv4Interface {
+		IPv4Interface::new(
+			IPv4::try_from("192.168.10.15").unwrap(),
+			IPv4Mask::new(24).unwrap(),
+		)
+	}
+
+	#[test]
+	fn new_test() {
+		let fixture = fixture();
+		assert_eq!(fixture.address(), &IPv4::try_from("192.168.10.15").unwrap());
+		assert_eq!(fixture.subnet_mask(), &IPv4Mask::new(24).unwrap());
+	}
+
+	#[test]
+	fn network_test() {
+		let actual = fixture().network();
+		assert_eq!(format!("{}", actual), "192.168.10.0/24");
+	}
+
+	#[test]
+	fn broadcast_test() {
+		assert_eq!(
+			fixture().broadcast(),
+			IPv4::try_from("192.168.10.255").unwrap()
+		);
+	}
+
+	#[test]
+	fn is_network_address_test() {
+		assert!(!fixture().is_network_address());
+
+		let fixture = IPv4Interface::try_from("192.168.10.0/24").unwrap();
+		assert!(fixture.is_network_address());
+	}
+
+	#[test]
+	fn try_from_str_test() {
+		let actual = IPv4Interface::try_from("192.168.10.15/255.255.255.0").unwrap();
+		assert!(actual == fixture());
+
+		let actual: IPv4Interface = "192.168.10.15".parse().unwrap();
+		assert_eq!(actual.subnet_mask().cidr(), 32);
+
+		assert!(matches!(
+			IPv4Interface::try_from("192.168.10/24"),
+			Err(CidrError::AddressError(_))
+		));
+		assert!(matches!(
+			IPv4Interface::try_from("192.168.10.15/33"),
+			Err(CidrError::PrefixError(_))
+		));
+	}
+
+	#[test]
+	fn parse_with_test() {
+		let actual = IPv4Interface::parse_with("192.168.10.15/24", IPv4ParseMode::Strict).unwrap();
+		assert_eq!(actual, fixture());
+
+		let actual = IPv4Interface::parse_with("0xc0a80a0f/24", IPv4ParseMode::InetAton).unwrap();
+		assert_eq!(actual, fixture());
+
+		assert_eq!(
+			IPv4Interface::parse_with("192.168.10.015/24", IPv4ParseMode::Strict),
+			Err(CidrError::AddressError(Ipv4Error::LeadingZero {
+				input: "192.168.10.015".to_string(),
+				position: 4,
+			}))
+		);
+		assert_eq!(
+			IPv4Interface::parse_with("192.168.10.15/024", IPv4ParseMode::Strict),
+			Err(CidrError::PrefixError(IPv4MaskError::FormatError(
+				"024".to_string()
+			)))
+		);
+	}
+
+	#[test]
+	fn debug_test() {
+		assert_eq!(format!("{:?}", fixture()), "192.168.10.15/24");
+	}
+
+	#[test]
+	fn display_test() {
+		assert_eq!(format!("{}", fixture()), "192.168.10.15/24");
+	}
+}
@@ -1,12 +1,15 @@
 use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
 
 use once_cell::sync::Lazy;
 
 use crate::ipv4::IPv4;
 use crate::ipv4_mask_error::IPv4MaskError;
+use crate::ipv4_parse_mode::IPv4ParseMode;
 
-static TABLE: Lazy<[IPv4; 32]> = Lazy::new(|| {
+static TABLE: Lazy<[IPv4; 33]> = Lazy::new(|| {
 	[
+		IPv4::from(0x00000000u32),
 		IPv4::from(0x80000000u32),
 		IPv4::from(0xc0000000u32),
 		IPv4::from(0xe0000000u32),
@@ -42,18 +45,50 @@ static TABLE: Lazy<[IPv4; 32]> = Lazy::new(|| {
 	]
 });
 
-#[derive(Eq, PartialEq, Copy, Clone)]
+/// A contiguous IPv4 subnet mask, parsed from a prefix length or a dotted
+/// mask.
+///
+/// ```
+/// use cidr_checker::IPv4Mask;
+///
+/// assert_eq!(IPv4Mask::try_from("255.255.255.0")?, IPv4Mask::new(24)?);
+/// assert_eq!(IPv4Mask::try_from("/24")?.cidr(), 24);
+/// assert!(IPv4Mask::try_from("255.0.255.0").is_err());
+/// # Ok::<(), cidr_checker::IPv4MaskError>(())
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
 pub struct IPv4Mask(IPv4, u8);
 
 impl IPv4Mask {
 	pub fn new(cidr: u8) -> Result<Self, IPv4MaskError> {
-		if cidr > 0 && cidr <= 32 {
-			Ok(IPv4Mask(TABLE[(cidr - 1) as usize], cidr))
+		if cidr <= 32 {
+			Ok(IPv4Mask(TABLE[cidr as usize], cidr))
 		} else {
-			Err(IPv4MaskError::CidrOutOfRange)
+			Err(IPv4MaskError::CidrOutOfRange(cidr as u32))
 		}
 	}
 
+	/// Parses `value` like `try_from(&str)`. `Strict` accepts only a decimal
+	/// prefix length without sign or leading zero, or a dotted mask whose
+	/// octets `IPv4::parse_with` accepts strictly; the other modes read the
+	/// mask as `try_from(&str)` does.
+	pub fn parse_with(value: &str, mode: IPv4ParseMode) -> Result<Self, IPv4MaskError> {
+		if mode != IPv4ParseMode::Strict {
+			return IPv4Mask::try_from(value);
+		}
+
+		let format_error = || IPv4MaskError::FormatError(value.to_string());
+		let prefix = value.strip_prefix('/').unwrap_or(value);
+
+		if prefix.contains('.') {
+			IPv4::parse_with(prefix, mode).map_err(|_| format_error())?;
+		} else if prefix.len() > 1 && prefix.starts_with('0') {
+			return Err(format_error());
+		}
+
+		IPv4Mask::try_from(value)
+	}
+
 	pub fn cidr(&self) -> u8 {
 		self.1
 	}
@@ -82,17 +117,53 @@ impl TryFrom<&IPv4> for IPv4Mask {
 	type Error = IPv4MaskError;
 
 	fn try_from(value: &IPv4) -> Result<Self, Self::Error> {
-		let mut cidr = 1u8;
-
-		for expected in TABLE.iter() {
+		for (cidr, expected) in (0u8..).zip(TABLE.iter()) {
 			if value == expected {
 				return Ok(IPv4Mask::new(cidr).unwrap());
 			}
+		}
+
+		Err(IPv4MaskError::InvalidSubnetMask(*value))
+	}
+}
+
+impl TryFrom<&str> for IPv4Mask {
+	type Error = IPv4MaskError;
 
-			cidr += 1;
+	/// Parses a prefix length (`24` or `/24`), a dotted mask (`255.255.255.0`)
+	/// or a hexadecimal mask (`0xffffff00`).
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		let format_error = || IPv4MaskError::FormatError(value.to_string());
+		let value = value.strip_prefix('/').unwrap_or(value);
+
+		if let Some(hex) = value
+			.strip_prefix("0x")
+			.or_else(|| value.strip_prefix("0X"))
+		{
+			if hex.is_empty() || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+				return Err(format_error());
+			}
+			let mask = u32::from_str_radix(hex, 16).map_err(|_| format_error())?;
+			IPv4Mask::try_from(&IPv4::from(mask))
+		} else if value.contains('.') {
+			let mask = IPv4::try_from(value).map_err(|_| format_error())?;
+			IPv4Mask::try_from(&mask)
+		} else {
+			if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+				return Err(format_error());
+			}
+			let cidr = value.parse::<u32>().map_err(|_| format_error())?;
+			let cidr = u8::try_from(cidr).map_err(|_| IPv4MaskError::CidrOutOfRange(cidr))?;
+			IPv4Mask::new(cidr)
 		}
+	}
+}
+
+impl FromStr for IPv4Mask {
+	type Err = IPv4MaskError;
 
-		Err(IPv4MaskError::InvalidSubnetMask)
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		IPv4Mask::try_from(s)
 	}
 }
 
@@ -115,9 +186,11 @@ mod tests {
 	use crate::ipv4::IPv4;
 	use crate::ipv4_mask::IPv4Mask;
 	use crate::ipv4_mask_error::IPv4MaskError;
+	use crate::ipv4_parse_mode::IPv4ParseMode;
 
-	static MASK: Lazy<[IPv4; 32]> = Lazy::new(|| {
+	static MASK: Lazy<[IPv4; 33]> = Lazy::new(|| {
 		[
+			IPv4::try_from("0.0.0.0").unwrap(),
 			IPv4::try_from("128.0.0.0").unwrap(),
 			IPv4::try_from("192.0.0.0").unwrap(),
 			IPv4::try_from("224.0.0.0").unwrap(),
@@ -154,51 +227,45 @@ mod tests {
 	});
 
 	fn assert_error<T>(actual: Result<T, IPv4MaskError>, expected: IPv4MaskError) {
-		fn to_ordinal(e: IPv4MaskError) -> usize {
-			match e {
-				IPv4MaskError::CidrOutOfRange => 1,
-				IPv4MaskError::FormatError => 2,
-				IPv4MaskError::InvalidSubnetMask => 3,
-			}
+		match actual {
+			Ok(_) => unreachable!(),
+			Err(err) => assert_eq!(err, expected),
 		}
+	}
 
-		if let Err(err) = actual {
-			assert_eq!(to_ordinal(err), to_ordinal(expected))
-		} else {
-			unreachable!()
-		}
+	fn format_error(input: &str) -> IPv4MaskError {
+		IPv4MaskError::FormatError(input.to_string())
 	}
 
 	#[test]
 	fn new_test() {
-		for i in 1..=32 {
+		for i in 0..=32 {
 			let actual = IPv4Mask::new(i);
 			assert!(actual.is_ok());
 			assert_eq!(actual.unwrap().cidr(), i);
 		}
 
-		assert_error(IPv4Mask::new(33), IPv4MaskError::CidrOutOfRange);
-		assert_error(IPv4Mask::new(0), IPv4MaskError::CidrOutOfRange);
+		assert_error(IPv4Mask::new(33), IPv4MaskError::CidrOutOfRange(33));
 	}
 
 	#[test]
 	fn from_subnet_mask_test() {
-		let mut cidr = 1;
-
-		for addr in MASK.iter() {
+		for (cidr, addr) in (0..).zip(MASK.iter()) {
 			let fixture = IPv4Mask::try_from(addr).unwrap();
 
 			assert_eq!(cidr, fixture.cidr());
-			cidr += 1;
 		}
 
 		let fixture = IPv4Mask::try_from(&IPv4::try_from("128.1.0.1").unwrap());
-		assert_error(fixture, IPv4MaskError::InvalidSubnetMask);
+		assert_error(
+			fixture,
+			IPv4MaskError::InvalidSubnetMask(IPv4::try_from("128.1.0.1").unwrap()),
+		);
 	}
 
 	#[test]
 	fn debug_test() {
-		for i in 1u8..=32 {
+		for i in 0u8..=32 {
 			let fixture = IPv4Mask::new(i).unwrap();
 			assert_eq!(format!("{:?}", fixture), format!("/{}", i))
 		}
@@ -206,7 +273,7 @@ mod tests {
 
 	#[test]
 	fn display_test() {
-		for i in 1u8..=32 {
+		for i in 0u8..=32 {
 			let fixture = IPv4Mask::new(i).unwrap();
 			assert_eq!(format!("{:}", fixture), format!("/{}", i))
 		}
@@ -214,9 +281,9 @@ mod tests {
 
 	#[test]
 	fn subnet_mask_test() {
-		for i in 1u8..=32 {
+		for i in 0u8..=32 {
 			let fixture = IPv4Mask::new(i).unwrap();
-			let expected = &MASK[(i - 1) as usize];
+			let expected = &MASK[i as usize];
 
 			assert_eq!(fixture.mask_address(), expected)
 		}
@@ -259,4 +326,131 @@ mod tests {
 		assert!(!(a == b));
 		assert!(a != b);
 	}
+
+	#[test]
+	fn try_from_str_test() {
+		let expected = IPv4Mask::new(24).unwrap();
+
+		assert_eq!(IPv4Mask::try_from("/24").unwrap(), expected);
+		assert_eq!(IPv4Mask::try_from("24").unwrap(), expected);
+		assert_eq!(IPv4Mask::try_from("255.255.255.0").unwrap(), expected);
+		assert_eq!(IPv4Mask::try_from("0xffffff00").unwrap(), expected);
+		assert_eq!(IPv4Mask::try_from("0XFFFFFF00").unwrap(), expected);
+
+		assert_eq!(IPv4Mask::try_from("/0").unwrap().cidr(), 0);
+		assert_eq!(IPv4Mask::try_from("0.0.0.0").unwrap().cidr(), 0);
+		assert_eq!(IPv4Mask::try_from("0x0").unwrap().cidr(), 0);
+
+		for (cidr, addr) in (0..).zip(MASK.iter()) {
+			let fixture = IPv4Mask::try_from(format!("{}", addr).as_str()).unwrap();
+			assert_eq!(cidr, fixture.cidr());
+		}
+	}
+
+	#[test]
+	fn try_from_str_error_test() {
+		assert_error(IPv4Mask::try_from(""), format_error(""));
+		assert_error(IPv4Mask::try_from("/"), format_error("/"));
+		assert_error(IPv4Mask::try_from("abc"), format_error("abc"));
+		assert_error(
+			IPv4Mask::try_from("255.255.255"),
+			format_error("255.255.255"),
+		);
+		assert_error(
+			IPv4Mask::try_from("255.255.255.256"),
+			format_error("255.255.255.256"),
+		);
+		assert_error(IPv4Mask::try_from("0x"), format_error("0x"));
+		assert_error(IPv4Mask::try_from("+24"), format_error("+24"));
+		assert_error(IPv4Mask::try_from("/+24"), format_error("/+24"));
+		assert_error(
+			IPv4Mask::try_from("0x+ffffff00"),
+			format_error("0x+ffffff00"),
+		);
+		assert_error(IPv4Mask::try_from("-0"), format_error("-0"));
+		assert_error(
+			IPv4Mask::try_from("0xfffffff00"),
+			format_error("0xfffffff00"),
+		);
+
+		assert_error(IPv4Mask::try_from("33"), IPv4MaskError::CidrOutOfRange(33));
+		assert_error(
+			IPv4Mask::try_from("/300"),
+			IPv4MaskError::CidrOutOfRange(300),
+		);
+
+		assert_error(
+			IPv4Mask::try_from("255.0.255.0"),
+			IPv4MaskError::InvalidSubnetMask(IPv4::try_from("255.0.255.0").unwrap()),
+		);
+		assert_error(
+			IPv4Mask::try_from("0xff00ff00"),
+			IPv4MaskError::InvalidSubnetMask(IPv4::from(0xff00ff00u32)),
+		);
+	}
+
+	#[test]
+	fn parse_with_test() {
+		let expected = IPv4Mask::new(24).unwrap();
+
+		for value in ["24", "/24", "255.255.255.0"] {
+			let actual = IPv4Mask::parse_with(value, IPv4ParseMode::Strict).unwrap();
+			assert_eq!(actual, expected);
+		}
+		assert_eq!(
+			IPv4Mask::parse_with("0", IPv4ParseMode::Strict)
+				.unwrap()
+				.cidr(),
+			0
+		);
+
+		for value in ["08", "/08", "+8", "/+8", "0xffffff00", "255.255.255.000"] {
+			assert_error(
+				IPv4Mask::parse_with(value, IPv4ParseMode::Strict),
+				format_error(value),
+			);
+		}
+		assert_error(
+			IPv4Mask::parse_with("255.0.255.0", IPv4ParseMode::Strict),
+			IPv4MaskError::InvalidSubnetMask(IPv4::try_from("255.0.255.0").unwrap()),
+		);
+
+		let actual = IPv4Mask::parse_with("/08", IPv4ParseMode::Lenient).unwrap();
+		assert_eq!(actual.cidr(), 8);
+		let actual = IPv4Mask::parse_with("0xffffff00", IPv4ParseMode::InetAton).unwrap();
+		assert_eq!(actual, expected);
+	}
+
+	#[test]
+	fn from_str_test() {
+		let fixture: IPv4Mask = "/16".parse().unwrap();
+		assert_eq!(fixture.cidr(), 16);
+
+		assert_error(
+			"0x0f".parse::<IPv4Mask>(),
+			IPv4MaskError::InvalidSubnetMask(IPv4::from(0x0fu32)),
+		);
+	}
+
+	#[test]
+	fn zero_prefix_test() {
+		let mask = IPv4Mask::new(0).unwrap();
+		let addr = IPv4::try_from("192.168.10.102").unwrap();
+
+		assert_eq!(mask.mask_address(), &IPv4::from(0));
+		assert_eq!(mask.network_address(&addr), IPv4::from(0));
+		assert_eq!(mask.broadcast_address(&addr), IPv4::from(0xffffffff));
+	}
+
+	#[test]
+	fn ord_test() {
+		let mut actual: Vec<_> = [24u8, 0, 32, 8]
+			.iter()
+			.map(|i| IPv4Mask::new(*i).unwrap())
+			.collect();
+		actual.sort();
+
+		let actual: Vec<_> = actual.iter().map(|m| m.cidr()).collect();
+		assert_eq!(actual, [0, 8, 24, 32]);
+	}
 }
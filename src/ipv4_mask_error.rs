@@ -1,68 +1,68 @@
-use std::fmt::{Debug, Display, Formatter};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
-type FmtResult = std::fmt::Result;
+use crate::ipv4::IPv4;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum IPv4MaskError {
-	CidrOutOfRange,
-	FormatError,
-	InvalidSubnetMask,
-}
-
-impl IPv4MaskError {
-	fn format(&self, f: &mut Formatter<'_>) -> FmtResult {
-		let scr = match self {
-			IPv4MaskError::CidrOutOfRange => "IPv4MaskError::CidrOutOfRange",
-			IPv4MaskError::FormatError => "IPv4MaskError::FormatError",
-			IPv4MaskError::InvalidSubnetMask => "IPv4MaskError::InvalidSubnetMask",
-		};
-
-		write!(f, "{}", scr)
-	}
-}
-
-impl Debug for IPv4MaskError {
-	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		self.format(f)
-	}
+	CidrOutOfRange(u32),
+	FormatError(String),
+	InvalidSubnetMask(IPv4),
 }
 
 impl Display for IPv4MaskError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		self.format(f)
+		match self {
+			IPv4MaskError::CidrOutOfRange(cidr) => {
+				write!(
+					f,
+					"prefix length {} is out of range (expected 0 to 32)",
+					cidr
+				)
+			}
+			IPv4MaskError::FormatError(input) => {
+				write!(f, "\"{}\" is not a prefix length or subnet mask", input)
+			}
+			IPv4MaskError::InvalidSubnetMask(mask) => write!(
+				f,
+				"{} is not a subnet mask: its one bits must be contiguous",
+				mask
+			),
+		}
 	}
 }
 
+impl Error for IPv4MaskError {}
+
 #[cfg(test)]
 mod tests {
+	use crate::ipv4::IPv4;
 	use crate::ipv4_mask_error::IPv4MaskError;
 
 	#[test]
-	fn debug_test() {
-		let fixture = IPv4MaskError::CidrOutOfRange;
-		let actual = format!("{:?}", fixture);
-		assert_eq!(actual, "IPv4MaskError::CidrOutOfRange");
+	fn display_test() {
+		let fixture = IPv4MaskError::CidrOutOfRange(33);
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"prefix length 33 is out of range (expected 0 to 32)"
+		);
 
-		let fixture = IPv4MaskError::FormatError;
-		let actual = format!("{:?}", fixture);
-		assert_eq!(actual, "IPv4MaskError::FormatError");
+		let fixture = IPv4MaskError::FormatError("/x".to_string());
+		let actual = format!("{}", fixture);
+		assert_eq!(actual, "\"/x\" is not a prefix length or subnet mask");
 
-		let fixture = IPv4MaskError::InvalidSubnetMask;
-		let actual = format!("{:?}", fixture);
-		assert_eq!(actual, "IPv4MaskError::InvalidSubnetMask");
+		let fixture = IPv4MaskError::InvalidSubnetMask(IPv4::try_from("255.0.255.0").unwrap());
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"255.0.255.0 is not a subnet mask: its one bits must be contiguous"
+		);
 	}
 
 	#[test]
-	fn display_test() {
-		let fixture = IPv4MaskError::CidrOutOfRange;
-		let actual = format!("{:}", fixture);
-		assert_eq!(actual, "IPv4MaskError::CidrOutOfRange");
-
-		let fixture = IPv4MaskError::FormatError;
-		let actual = format!("{:}", fixture);
-		assert_eq!(actual, "IPv4MaskError::FormatError");
-
-		let fixture = IPv4MaskError::InvalidSubnetMask;
-		let actual = format!("{:}", fixture);
-		assert_eq!(actual, "IPv4MaskError::InvalidSubnetMask");
+	fn error_test() {
+		let fixture: Box<dyn std::error::Error> = Box::new(IPv4MaskError::CidrOutOfRange(33));
+		assert!(fixture.source().is_none());
 	}
 }
@@ -1,19 +1,43 @@
+use crate::addresses::Addresses;
+use crate::cidr::Cidr;
 use crate::ipv4::IPv4;
+use crate::ipv4_mask::IPv4Mask;
+use crate::ipv4_network_range_error::IPv4NetworkRangeError;
 use std::fmt::{Debug, Display, Formatter};
 
-#[derive(Eq, PartialEq)]
+/// An inclusive range of IPv4 addresses, not necessarily aligned to a block.
+///
+/// ```
+/// use cidr_checker::{IPv4, IPv4NetworkRange};
+///
+/// let range = IPv4NetworkRange::new(
+///     IPv4::try_from("10.0.0.5")?,
+///     IPv4::try_from("10.0.0.12")?,
+/// )?;
+/// assert!(range.contain(&IPv4::try_from("10.0.0.8")?));
+/// assert_eq!(range.size(), 8);
+///
+/// // Not a single block, so no address is a network or broadcast address.
+/// assert_eq!(range.usable_hosts(), 8);
+/// assert_eq!(range.first_host().to_string(), "10.0.0.5");
+///
+/// let cidrs: Vec<_> = range.to_cidrs().iter().map(|c| c.to_string()).collect();
+/// assert_eq!(cidrs, ["10.0.0.5/32", "10.0.0.6/31", "10.0.0.8/30", "10.0.0.12/32"]);
+/// # Ok::<(), cidr_checker::Error>(())
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
 pub struct IPv4NetworkRange {
 	begin: IPv4,
 	end: IPv4,
 }
 
 impl IPv4NetworkRange {
-	pub(crate) fn new(begin: IPv4, end: IPv4) -> IPv4NetworkRange {
+	pub fn new(begin: IPv4, end: IPv4) -> Result<IPv4NetworkRange, IPv4NetworkRangeError> {
 		if begin.to_u32() > end.to_u32() {
-			panic!("begin must be less than or equal to end");
+			Err(IPv4NetworkRangeError::BeginGreaterThanEnd { begin, end })
+		} else {
+			Ok(IPv4NetworkRange { begin, end })
 		}
-
-		IPv4NetworkRange { begin, end }
 	}
 
 	pub fn contain(&self, address: &IPv4) -> bool {
@@ -31,6 +55,91 @@ impl IPv4NetworkRange {
 	pub fn end(&self) -> &IPv4 {
 		&self.end
 	}
+
+	pub fn size(&self) -> u64 {
+		(self.end.to_u32() - self.begin.to_u32()) as u64 + 1
+	}
+
+	/// Only a range that is exactly one block of /30 or shorter has a network
+	/// and a broadcast address to leave out. Every address of any other range,
+	/// including /32 host routes and RFC 3021 /31 links, is usable.
+	pub fn usable_hosts(&self) -> u64 {
+		if self.has_network_and_broadcast() {
+			self.size() - 2
+		} else {
+			self.size()
+		}
+	}
+
+	pub fn first_host(&self) -> IPv4 {
+		if self.has_network_and_broadcast() {
+			self.begin + 1
+		} else {
+			self.begin
+		}
+	}
+
+	pub fn last_host(&self) -> IPv4 {
+		if self.has_network_and_broadcast() {
+			self.end - 1
+		} else {
+			self.end
+		}
+	}
+
+	pub fn addresses(&self) -> Addresses {
+		Addresses::new(&self.begin, &self.end)
+	}
+
+	/// Only the usable hosts, with the same rules as `usable_hosts`.
+	pub fn hosts(&self) -> Addresses {
+		Addresses::new(&self.first_host(), &self.last_host())
+	}
+
+	/// Decomposes the range into the minimal list of CIDR blocks that cover
+	/// exactly the same addresses, in ascending order.
+	pub fn to_cidrs(self) -> Vec<Cidr> {
+		let mut result = Vec::new();
+		let mut current = self.begin.to_u32() as u64;
+		let end = self.end.to_u32() as u64;
+
+		while current <= end {
+			let alignment = current.trailing_zeros().min(32);
+			let fit = (end - current + 1).ilog2();
+			let bits = alignment.min(fit);
+
+			let mask = IPv4Mask::new((32 - bits) as u8).unwrap();
+			result.push(Cidr::new(IPv4::from(current as u32), mask).unwrap());
+
+			current += 1 << bits;
+		}
+
+		result
+	}
+
+	/// Whether the range is a single CIDR block shorter than /31.
+	fn has_network_and_broadcast(&self) -> bool {
+		let size = self.size();
+		size > 2 && size.is_power_of_two() && (self.begin.to_u32() as u64).is_multiple_of(size)
+	}
+}
+
+impl IntoIterator for IPv4NetworkRange {
+	type Item = IPv4;
+	type IntoIter = Addresses;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.addresses()
+	}
+}
+
+impl IntoIterator for &IPv4NetworkRange {
+	type Item = IPv4;
+	type IntoIter = Addresses;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.addresses()
+	}
 }
 
 impl Debug for IPv4NetworkRange {
@@ -49,12 +158,13 @@ impl Display for IPv4NetworkRange {
 mod tests {
 	use super::IPv4NetworkRange;
 	use crate::ipv4::IPv4;
+	use crate::ipv4_network_range_error::IPv4NetworkRangeError;
 	use once_cell::sync::Lazy;
 	static EXPECTED_BEGIN: Lazy<IPv4> = Lazy::new(|| IPv4::try_from("192.168.112.0").unwrap());
 	static EXPECTED_END: Lazy<IPv4> = Lazy::new(|| IPv4::try_from("192.168.127.255").unwrap());
 
 	fn fixture() -> IPv4NetworkRange {
-		IPv4NetworkRange::new(*EXPECTED_BEGIN, *EXPECTED_END)
+		IPv4NetworkRange::new(*EXPECTED_BEGIN, *EXPECTED_END).unwrap()
 	}
 
 	fn assert(actual: &IPv4NetworkRange, expected_begin: &IPv4, expected_end: &IPv4) {
@@ -64,7 +174,7 @@ mod tests {
 
 	#[test]
 	fn new_test() {
-		let actual = IPv4NetworkRange::new(*EXPECTED_BEGIN, *EXPECTED_END);
+		let actual = IPv4NetworkRange::new(*EXPECTED_BEGIN, *EXPECTED_END).unwrap();
 		assert(&actual, &EXPECTED_BEGIN, &EXPECTED_END);
 	}
 
@@ -76,10 +186,10 @@ mod tests {
 		assert!(fixture.contain(&EXPECTED_END));
 		assert!(fixture.contain(&IPv4::try_from("192.168.121.101").unwrap()));
 
-		let addr = IPv4::from(&EXPECTED_BEGIN.to_u32() - 1);
+		let addr = EXPECTED_BEGIN.predecessor().unwrap();
 		assert!(!fixture.contain(&addr));
 
-		let addr = IPv4::from(&EXPECTED_END.to_u32() + 1);
+		let addr = EXPECTED_END.successor().unwrap();
 		assert!(!fixture.contain(&addr));
 	}
 
@@ -96,9 +206,15 @@ mod tests {
 	}
 
 	#[test]
-	#[should_panic]
-	fn new_test_panic() {
-		let _ = IPv4NetworkRange::new(*EXPECTED_END, *EXPECTED_BEGIN);
+	fn new_error_test() {
+		let actual = IPv4NetworkRange::new(*EXPECTED_END, *EXPECTED_BEGIN);
+		assert_eq!(
+			actual.unwrap_err(),
+			IPv4NetworkRangeError::BeginGreaterThanEnd {
+				begin: *EXPECTED_END,
+				end: *EXPECTED_BEGIN,
+			}
+		);
 	}
 
 	#[test]
@@ -107,4 +223,163 @@ mod tests {
 		assert_eq!(&fix.begin, fix.begin());
 		assert_eq!(&fix.end, fix.end());
 	}
+
+	#[test]
+	fn size_test() {
+		assert_eq!(fixture().size(), 4096);
+
+		let fixture = IPv4NetworkRange::new(*EXPECTED_BEGIN, *EXPECTED_BEGIN).unwrap();
+		assert_eq!(fixture.size(), 1);
+
+		let fixture = IPv4NetworkRange::new(IPv4::from(0), IPv4::from(0xffffffff)).unwrap();
+		assert_eq!(fixture.size(), 1 << 32);
+	}
+
+	#[test]
+	fn usable_hosts_test() {
+		let fixture = fixture();
+		assert_eq!(fixture.usable_hosts(), 4094);
+		assert_eq!(
+			fixture.first_host(),
+			IPv4::try_from("192.168.112.1").unwrap()
+		);
+		assert_eq!(
+			fixture.last_host(),
+			IPv4::try_from("192.168.127.254").unwrap()
+		);
+	}
+
+	#[test]
+	fn usable_hosts_point_to_point_test() {
+		let begin = IPv4::try_from("192.168.112.0").unwrap();
+		let end = IPv4::try_from("192.168.112.1").unwrap();
+		let fixture = IPv4NetworkRange::new(begin, end).unwrap();
+
+		assert_eq!(fixture.usable_hosts(), 2);
+		assert_eq!(fixture.first_host(), begin);
+		assert_eq!(fixture.last_host(), end);
+	}
+
+	#[test]
+	fn usable_hosts_single_address_test() {
+		let fixture = IPv4NetworkRange::new(*EXPECTED_BEGIN, *EXPECTED_BEGIN).unwrap();
+
+		assert_eq!(fixture.usable_hosts(), 1);
+		assert_eq!(fixture.first_host(), *EXPECTED_BEGIN);
+		assert_eq!(fixture.last_host(), *EXPECTED_BEGIN);
+	}
+
+	#[test]
+	fn usable_hosts_unaligned_test() {
+		let begin = IPv4::try_from("10.0.0.5").unwrap();
+		let end = IPv4::try_from("10.0.0.12").unwrap();
+		let fixture = IPv4NetworkRange::new(begin, end).unwrap();
+
+		assert_eq!(fixture.usable_hosts(), 8);
+		assert_eq!(fixture.first_host(), begin);
+		assert_eq!(fixture.last_host(), end);
+		assert_eq!(
+			fixture.hosts().collect::<Vec<_>>(),
+			fixture.addresses().collect::<Vec<_>>()
+		);
+
+		// Block-sized but not aligned to a block boundary.
+		let begin = IPv4::try_from("10.0.0.4").unwrap();
+		let end = IPv4::try_from("10.0.0.11").unwrap();
+		let fixture = IPv4NetworkRange::new(begin, end).unwrap();
+		assert_eq!(fixture.usable_hosts(), 8);
+
+		let begin = IPv4::try_from("10.0.0.8").unwrap();
+		let end = IPv4::try_from("10.0.0.11").unwrap();
+		let fixture = IPv4NetworkRange::new(begin, end).unwrap();
+		assert_eq!(fixture.usable_hosts(), 2);
+		assert_eq!(fixture.first_host(), IPv4::try_from("10.0.0.9").unwrap());
+		assert_eq!(fixture.last_host(), IPv4::try_from("10.0.0.10").unwrap());
+	}
+
+	#[test]
+	fn ord_test() {
+		let wide = IPv4NetworkRange::new(*EXPECTED_BEGIN, *EXPECTED_END).unwrap();
+		let narrow = IPv4NetworkRange::new(*EXPECTED_BEGIN, *EXPECTED_BEGIN).unwrap();
+		let copied = wide;
+
+		assert!(narrow < wide);
+		assert!(copied == wide);
+	}
+
+	fn to_cidrs(begin: &str, end: &str) -> Vec<String> {
+		let begin = IPv4::try_from(begin).unwrap();
+		let end = IPv4::try_from(end).unwrap();
+
+		IPv4NetworkRange::new(begin, end)
+			.unwrap()
+			.to_cidrs()
+			.iter()
+			.map(|c| format!("{}", c))
+			.collect()
+	}
+
+	#[test]
+	fn to_cidrs_test() {
+		assert_eq!(
+			to_cidrs("10.0.0.5", "10.0.1.17"),
+			[
+				"10.0.0.5/32",
+				"10.0.0.6/31",
+				"10.0.0.8/29",
+				"10.0.0.16/28",
+				"10.0.0.32/27",
+				"10.0.0.64/26",
+				"10.0.0.128/25",
+				"10.0.1.0/28",
+				"10.0.1.16/31",
+			]
+		);
+
+		assert_eq!(
+			to_cidrs("192.168.112.0", "192.168.127.255"),
+			["192.168.112.0/20"]
+		);
+		assert_eq!(to_cidrs("10.0.0.1", "10.0.0.1"), ["10.0.0.1/32"]);
+		assert_eq!(to_cidrs("0.0.0.0", "255.255.255.255"), ["0.0.0.0/0"]);
+		assert_eq!(to_cidrs("0.0.0.1", "255.255.255.255").len(), 32);
+		assert_eq!(
+			to_cidrs("255.255.255.254", "255.255.255.255"),
+			["255.255.255.254/31"]
+		);
+	}
+
+	#[test]
+	fn addresses_test() {
+		let begin = IPv4::try_from("192.168.112.254").unwrap();
+		let end = IPv4::try_from("192.168.113.1").unwrap();
+		let fixture = IPv4NetworkRange::new(begin, end).unwrap();
+
+		let actual: Vec<_> = fixture.addresses().collect();
+		assert_eq!(actual.len(), 4);
+		assert_eq!(actual[0], begin);
+		assert_eq!(actual[3], end);
+
+		let actual: Vec<_> = fixture.into_iter().collect();
+		assert_eq!(actual.len(), 4);
+
+		let mut count = 0;
+		for _ in &fixture {
+			count += 1;
+		}
+		assert_eq!(count, 4);
+	}
+
+	#[test]
+	fn hosts_test() {
+		let fixture = fixture();
+		let mut actual = fixture.hosts();
+
+		assert_eq!(actual.remaining(), 4094);
+		assert_eq!(actual.next(), Some(fixture.first_host()));
+		assert_eq!(actual.next_back(), Some(fixture.last_host()));
+
+		let fixture = IPv4NetworkRange::new(*EXPECTED_BEGIN, *EXPECTED_BEGIN).unwrap();
+		assert_eq!(fixture.hosts().collect::<Vec<_>>(), [*EXPECTED_BEGIN]);
+	}
 }
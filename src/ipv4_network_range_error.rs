@@ -0,0 +1,40 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::ipv4::IPv4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IPv4NetworkRangeError {
+	BeginGreaterThanEnd { begin: IPv4, end: IPv4 },
+}
+
+impl Display for IPv4NetworkRangeError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			IPv4NetworkRangeError::BeginGreaterThanEnd { begin, end } => {
+				write!(f, "range begin {} is greater than its end {}", begin, end)
+			}
+		}
+	}
+}
+
+impl Error for IPv4NetworkRangeError {}
+
+#[cfg(test)]
+mod tests {
+	use crate::ipv4::IPv4;
+	use crate::ipv4_network_range_error::IPv4NetworkRangeError;
+
+	#[test]
+	fn display_test() {
+		let fixture = IPv4NetworkRangeError::BeginGreaterThanEnd {
+			begin: IPv4::try_from("10.0.0.9").unwrap(),
+			end: IPv4::try_from("10.0.0.1").unwrap(),
+		};
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"range begin 10.0.0.9 is greater than its end 10.0.0.1"
+		);
+	}
+}
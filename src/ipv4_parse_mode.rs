@@ -0,0 +1,28 @@
+/// How strictly `IPv4::parse_with` reads dotted addresses. The modes differ
+/// only in what they accept; any address they all accept parses to the same
+/// value, except that `InetAton` reads a leading `0` as octal.
+///
+/// ```
+/// use cidr_checker::{IPv4, IPv4ParseMode};
+///
+/// let address = "010.001.000.001";
+/// assert!(IPv4::parse_with(address, IPv4ParseMode::Strict).is_err());
+/// assert_eq!(IPv4::parse_with(address, IPv4ParseMode::Lenient)?.to_string(), "10.1.0.1");
+/// assert_eq!(IPv4::parse_with(address, IPv4ParseMode::InetAton)?.to_string(), "8.1.0.1");
+/// # Ok::<(), cidr_checker::Ipv4Error>(())
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Default)]
+pub enum IPv4ParseMode {
+	/// Four decimal octets of 1 to 3 digits with no leading zeros, signs or
+	/// whitespace. Every address has exactly one accepted spelling, so use
+	/// this for input that is compared against ACLs.
+	Strict,
+	/// Four octets in anything `u8::from_str` accepts, so leading zeros are
+	/// read as decimal. This is what `IPv4::try_from(&str)` does.
+	#[default]
+	Lenient,
+	/// Everything `inet_aton(3)` accepts: one to four parts, each decimal,
+	/// octal with a leading `0` or hexadecimal with `0x`, the last part
+	/// filling the remaining bytes (`127.1`, `0x7f.1`, `2130706433`).
+	InetAton,
+}
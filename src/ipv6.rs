@@ -0,0 +1,448 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::net::{IpAddr, Ipv6Addr};
+use std::str::FromStr;
+
+use crate::ipv4::IPv4;
+use crate::ipv4_error::Ipv4Error;
+use crate::ipv4_parse_mode::IPv4ParseMode;
+use crate::ipv6_error::Ipv6Error;
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub struct IPv6(u128);
+
+impl IPv6 {
+	pub fn segment(&self, index: usize) -> Result<u16, Ipv6Error> {
+		if index > 7 {
+			Err(Ipv6Error::IndexOutOfRange(index))
+		} else {
+			Ok((self.0 >> ((7 - index) * 16)) as u16)
+		}
+	}
+
+	pub fn segments(&self) -> [u16; 8] {
+		let mut result = [0u16; 8];
+		for (i, segment) in result.iter_mut().enumerate() {
+			*segment = self.segment(i).unwrap();
+		}
+		result
+	}
+
+	pub fn to_u128(self) -> u128 {
+		self.0
+	}
+
+	/// The embedded address of an IPv4-mapped address (`::ffff:a.b.c.d`).
+	pub fn to_ipv4_mapped(self) -> Option<IPv4> {
+		if self.0 >> 32 == 0xffff {
+			Some(IPv4::from(self.0 as u32))
+		} else {
+			None
+		}
+	}
+
+	/// Formats as recommended by RFC 5952: lowercase, no leading zeros, the
+	/// longest run of two or more zero groups (the first one on a tie)
+	/// compressed to `::`, and IPv4-mapped addresses in mixed notation.
+	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		if let Some(ipv4) = self.to_ipv4_mapped() {
+			return write!(f, "::ffff:{}", ipv4);
+		}
+
+		let segments = self.segments();
+
+		let mut longest = (0usize, 0usize);
+		let mut current = (0usize, 0usize);
+		for (i, segment) in segments.iter().enumerate() {
+			if *segment != 0 {
+				current = (i + 1, 0);
+			} else {
+				current.1 += 1;
+				if current.1 > longest.1 {
+					longest = current;
+				}
+			}
+		}
+
+		if longest.1 < 2 {
+			return write_groups(f, &segments);
+		}
+
+		let (begin, len) = longest;
+		write_groups(f, &segments[..begin])?;
+		write!(f, "::")?;
+		write_groups(f, &segments[begin + len..])
+	}
+}
+
+fn write_groups(f: &mut Formatter<'_>, groups: &[u16]) -> std::fmt::Result {
+	for (i, group) in groups.iter().enumerate() {
+		if i > 0 {
+			write!(f, ":")?;
+		}
+		write!(f, "{:x}", group)?;
+	}
+	Ok(())
+}
+
+fn parse_group(input: &str, group: &str) -> Result<u16, Ipv6Error> {
+	if group.is_empty() {
+		Err(Ipv6Error::InvalidFormat(input.to_string()))
+	} else if group.len() > 4 || !group.chars().all(|c| c.is_ascii_hexdigit()) {
+		Err(invalid_value(input, group))
+	} else {
+		Ok(u16::from_str_radix(group, 16).unwrap())
+	}
+}
+
+fn invalid_value(input: &str, group: &str) -> Ipv6Error {
+	Ipv6Error::InvalidValue {
+		input: input.to_string(),
+		group: group.to_string(),
+	}
+}
+
+/// Parses the colon separated groups in `part` of `input`; the last one may
+/// be an embedded IPv4 address when `allow_ipv4` is set. That address is read
+/// strictly, as RFC 4291 and `std::net::Ipv6Addr` do, so it has no octal-like
+/// or signed spellings.
+fn parse_groups(input: &str, part: &str, allow_ipv4: bool) -> Result<Vec<u16>, Ipv6Error> {
+	let mut result = Vec::new();
+
+	if part.is_empty() {
+		return Ok(result);
+	}
+
+	let groups: Vec<_> = part.split(':').collect();
+	for (i, group) in groups.iter().enumerate() {
+		if group.contains('.') {
+			if !allow_ipv4 || i != groups.len() - 1 {
+				return Err(Ipv6Error::InvalidFormat(input.to_string()));
+			}
+
+			let ipv4 = IPv4::parse_with(group, IPv4ParseMode::Strict).map_err(|e| match e {
+				Ipv4Error::InvalidFormat(_) => Ipv6Error::InvalidFormat(input.to_string()),
+				_ => invalid_value(input, group),
+			})?;
+			result.push((ipv4.to_u32() >> 16) as u16);
+			result.push(ipv4.to_u32() as u16);
+		} else {
+			result.push(parse_group(input, group)?);
+		}
+	}
+
+	Ok(result)
+}
+
+impl From<u128> for IPv6 {
+	fn from(value: u128) -> Self {
+		IPv6(value)
+	}
+}
+
+impl From<[u16; 8]> for IPv6 {
+	fn from(value: [u16; 8]) -> Self {
+		IPv6(value.iter().fold(0u128, |acc, s| (acc << 16) | *s as u128))
+	}
+}
+
+impl From<Ipv6Addr> for IPv6 {
+	fn from(value: Ipv6Addr) -> Self {
+		IPv6(u128::from(value))
+	}
+}
+
+impl From<IPv6> for Ipv6Addr {
+	fn from(value: IPv6) -> Self {
+		Ipv6Addr::from(value.0)
+	}
+}
+
+impl From<IPv6> for IpAddr {
+	fn from(value: IPv6) -> Self {
+		IpAddr::V6(value.into())
+	}
+}
+
+impl TryFrom<&[u8]> for IPv6 {
+	type Error = Ipv6Error;
+
+	fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+		let bytes: [u8; 16] = value
+			.try_into()
+			.map_err(|_| Ipv6Error::InvalidArrayLength(value.len()))?;
+		Ok(IPv6(u128::from_be_bytes(bytes)))
+	}
+}
+
+impl TryFrom<&str> for IPv6 {
+	type Error = Ipv6Error;
+
+	/// Accepts every RFC 4291 text form, including `::` compression and a
+	/// trailing embedded IPv4 address. Zone IDs (`fe80::1%eth0`) are rejected.
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		if value.contains('%') {
+			return Err(Ipv6Error::ZoneIdNotSupported(value.to_string()));
+		}
+
+		let groups = match value.split_once("::") {
+			Some((head, tail)) => {
+				if tail.contains("::") {
+					return Err(Ipv6Error::InvalidFormat(value.to_string()));
+				}
+
+				let head = parse_groups(value, head, false)?;
+				let tail = parse_groups(value, tail, true)?;
+
+				if head.len() + tail.len() > 7 {
+					return Err(Ipv6Error::InvalidFormat(value.to_string()));
+				}
+
+				let mut groups = [0u16; 8];
+				groups[..head.len()].copy_from_slice(&head);
+				groups[8 - tail.len()..].copy_from_slice(&tail);
+				groups
+			}
+			None => parse_groups(value, value, true)?
+				.try_into()
+				.map_err(|_| Ipv6Error::InvalidFormat(value.to_string()))?,
+		};
+
+		Ok(IPv6::from(groups))
+	}
+}
+
+impl FromStr for IPv6 {
+	type Err = Ipv6Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		IPv6::try_from(s)
+	}
+}
+
+impl Debug for IPv6 {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+impl Display for IPv6 {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::net::Ipv6Addr;
+
+	use crate::ipv6::IPv6;
+	use crate::ipv6_error::Ipv6Error;
+
+	fn assert_err<T>(actual: Result<T, Ipv6Error>, expected: Ipv6Error) {
+		match actual {
+			Ok(_) => unreachable!(),
+			Err(err) => assert_eq!(err, expected),
+		}
+	}
+
+	fn invalid_value(input: &str, group: &str) -> Ipv6Error {
+		Ipv6Error::InvalidValue {
+			input: input.to_string(),
+			group: group.to_string(),
+		}
+	}
+
+	fn parse(value: &str) -> IPv6 {
+		IPv6::try_from(value).unwrap()
+	}
+
+	#[test]
+	fn try_from_str_test() {
+		assert_eq!(
+			parse("2001:db8:0:0:1:0:0:1").segments(),
+			[0x2001, 0xdb8, 0, 0, 1, 0, 0, 1]
+		);
+		assert_eq!(
+			parse("2001:DB8::1").segments(),
+			[0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]
+		);
+		assert_eq!(parse("::").to_u128(), 0);
+		assert_eq!(parse("::1").to_u128(), 1);
+		assert_eq!(parse("fe80::").segments(), [0xfe80, 0, 0, 0, 0, 0, 0, 0]);
+		assert_eq!(
+			parse("1:2:3:4:5:6:7::").segments(),
+			[1, 2, 3, 4, 5, 6, 7, 0]
+		);
+		assert_eq!(
+			parse("::ffff:192.0.2.1").segments(),
+			[0, 0, 0, 0, 0, 0xffff, 0xc000, 0x0201]
+		);
+		assert_eq!(
+			parse("64:ff9b::192.0.2.33").segments(),
+			[0x64, 0xff9b, 0, 0, 0, 0, 0xc000, 0x0221]
+		);
+		assert_eq!(
+			parse("1:2:3:4:5:6:1.2.3.4").segments(),
+			[1, 2, 3, 4, 5, 6, 0x0102, 0x0304]
+		);
+	}
+
+	#[test]
+	fn try_from_str_error_test() {
+		assert_err(IPv6::try_from(""), Ipv6Error::InvalidFormat("".to_string()));
+		assert_err(
+			IPv6::try_from("1:2:3:4:5:6:7"),
+			Ipv6Error::InvalidFormat("1:2:3:4:5:6:7".to_string()),
+		);
+		assert_err(
+			IPv6::try_from("1:2:3:4:5:6:7:8:9"),
+			Ipv6Error::InvalidFormat("1:2:3:4:5:6:7:8:9".to_string()),
+		);
+		assert_err(
+			IPv6::try_from("1:2:3:4::5:6:7:8"),
+			Ipv6Error::InvalidFormat("1:2:3:4::5:6:7:8".to_string()),
+		);
+		assert_err(
+			IPv6::try_from("1::2::3"),
+			Ipv6Error::InvalidFormat("1::2::3".to_string()),
+		);
+		assert_err(
+			IPv6::try_from(":1::"),
+			Ipv6Error::InvalidFormat(":1::".to_string()),
+		);
+		assert_err(
+			IPv6::try_from("1:::2"),
+			Ipv6Error::InvalidFormat("1:::2".to_string()),
+		);
+		assert_err(
+			IPv6::try_from("1.2.3.4::"),
+			Ipv6Error::InvalidFormat("1.2.3.4::".to_string()),
+		);
+		assert_err(
+			IPv6::try_from("::1.2.3.4:1"),
+			Ipv6Error::InvalidFormat("::1.2.3.4:1".to_string()),
+		);
+		assert_err(
+			IPv6::try_from("::1.2.3"),
+			Ipv6Error::InvalidFormat("::1.2.3".to_string()),
+		);
+
+		assert_err(
+			IPv6::try_from("2001:db8::12345"),
+			invalid_value("2001:db8::12345", "12345"),
+		);
+		assert_err(
+			IPv6::try_from("2001:db8::g"),
+			invalid_value("2001:db8::g", "g"),
+		);
+		assert_err(
+			IPv6::try_from("2001:db8::+1"),
+			invalid_value("2001:db8::+1", "+1"),
+		);
+		assert_err(
+			IPv6::try_from("::ffff:+1.2.3.4"),
+			invalid_value("::ffff:+1.2.3.4", "+1.2.3.4"),
+		);
+		assert_err(
+			IPv6::try_from("::ffff:01.2.3.4"),
+			invalid_value("::ffff:01.2.3.4", "01.2.3.4"),
+		);
+		assert_err(
+			IPv6::try_from("::1.2.3.256"),
+			invalid_value("::1.2.3.256", "1.2.3.256"),
+		);
+
+		assert_err(
+			IPv6::try_from("fe80::1%eth0"),
+			Ipv6Error::ZoneIdNotSupported("fe80::1%eth0".to_string()),
+		);
+	}
+
+	#[test]
+	fn display_test() {
+		let cases = [
+			(
+				"2001:0db8:0000:0000:0001:0000:0000:0001",
+				"2001:db8::1:0:0:1",
+			),
+			("2001:db8:0:0:0:0:2:1", "2001:db8::2:1"),
+			("2001:db8:0:1:1:1:1:1", "2001:db8:0:1:1:1:1:1"),
+			("2001:0:0:1:0:0:0:1", "2001:0:0:1::1"),
+			("2001:db8:0:0:1:0:0:1", "2001:db8::1:0:0:1"),
+			("2001:DB8::AAAA", "2001:db8::aaaa"),
+			("0:0:0:0:0:0:0:0", "::"),
+			("0:0:0:0:0:0:0:1", "::1"),
+			("1:0:0:0:0:0:0:0", "1::"),
+			("::ffff:c000:0201", "::ffff:192.0.2.1"),
+			("::c000:0201", "::c000:201"),
+		];
+
+		for (input, expected) in cases {
+			assert_eq!(format!("{}", parse(input)), expected);
+			assert_eq!(format!("{:?}", parse(input)), expected);
+		}
+	}
+
+	#[test]
+	fn display_matches_std_test() {
+		let mut seed = 0x9e37_79b9_7f4a_7c15u64;
+		for _ in 0..1000 {
+			let mut segments = [0u16; 8];
+			for segment in segments.iter_mut() {
+				seed ^= seed << 13;
+				seed ^= seed >> 7;
+				seed ^= seed << 17;
+				*segment = if seed.is_multiple_of(3) {
+					0
+				} else {
+					seed as u16
+				};
+			}
+
+			let fixture = IPv6::from(segments);
+			let std = Ipv6Addr::from(fixture);
+			assert_eq!(format!("{}", fixture), format!("{}", std));
+			assert_eq!(parse(&format!("{}", fixture)), fixture);
+		}
+	}
+
+	#[test]
+	fn segment_test() {
+		let fixture = parse("2001:db8::1");
+		assert_eq!(fixture.segment(0).unwrap(), 0x2001);
+		assert_eq!(fixture.segment(7).unwrap(), 1);
+		assert_err(fixture.segment(8), Ipv6Error::IndexOutOfRange(8));
+	}
+
+	#[test]
+	fn try_from_u8_slice_test() {
+		let bytes = [0x20u8, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+		assert_eq!(IPv6::try_from(&bytes[..]).unwrap(), parse("2001:db8::1"));
+		assert_err(
+			IPv6::try_from(&bytes[1..]),
+			Ipv6Error::InvalidArrayLength(15),
+		);
+	}
+
+	#[test]
+	fn std_net_test() {
+		let std = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+		let fixture = IPv6::from(std);
+
+		assert_eq!(fixture, parse("2001:db8::1"));
+		assert_eq!(Ipv6Addr::from(fixture), std);
+	}
+
+	#[test]
+	fn to_ipv4_mapped_test() {
+		let actual = parse("::ffff:192.0.2.1").to_ipv4_mapped().unwrap();
+		assert_eq!(format!("{}", actual), "192.0.2.1");
+		assert!(parse("::192.0.2.1").to_ipv4_mapped().is_none());
+	}
+
+	#[test]
+	fn ord_test() {
+		assert!(parse("::1") < parse("::2"));
+		assert!(parse("2001:db8::") < parse("fe80::"));
+	}
+}
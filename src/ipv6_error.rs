@@ -0,0 +1,83 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ipv6Error {
+	IndexOutOfRange(usize),
+	InvalidFormat(String),
+	InvalidArrayLength(usize),
+	/// `group` is the offending colon-separated group of `input`.
+	InvalidValue {
+		input: String,
+		group: String,
+	},
+	ZoneIdNotSupported(String),
+}
+
+impl Display for Ipv6Error {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Ipv6Error::IndexOutOfRange(index) => {
+				write!(f, "group index {} is out of range (expected 0 to 7)", index)
+			}
+			Ipv6Error::InvalidFormat(input) => write!(f, "\"{}\" is not an IPv6 address", input),
+			Ipv6Error::InvalidArrayLength(len) => {
+				write!(f, "an IPv6 address needs 16 bytes, got {}", len)
+			}
+			Ipv6Error::InvalidValue { input, group } => write!(
+				f,
+				"group \"{}\" of \"{}\" is not 1 to 4 hexadecimal digits or an IPv4 address",
+				group, input
+			),
+			Ipv6Error::ZoneIdNotSupported(input) => {
+				write!(f, "\"{}\" has a zone ID, which is not supported", input)
+			}
+		}
+	}
+}
+
+impl Error for Ipv6Error {}
+
+#[cfg(test)]
+mod tests {
+	use crate::ipv6_error::Ipv6Error;
+
+	#[test]
+	fn display_test() {
+		let actual = format!("{}", Ipv6Error::IndexOutOfRange(8));
+		assert_eq!(actual, "group index 8 is out of range (expected 0 to 7)");
+
+		let actual = format!("{}", Ipv6Error::InvalidFormat("1::2::3".to_string()));
+		assert_eq!(actual, "\"1::2::3\" is not an IPv6 address");
+
+		let actual = format!("{}", Ipv6Error::InvalidArrayLength(15));
+		assert_eq!(actual, "an IPv6 address needs 16 bytes, got 15");
+
+		let actual = format!(
+			"{}",
+			Ipv6Error::InvalidValue {
+				input: "2001:db8::g".to_string(),
+				group: "g".to_string()
+			}
+		);
+		assert_eq!(
+			actual,
+			"group \"g\" of \"2001:db8::g\" is not 1 to 4 hexadecimal digits or an IPv4 address"
+		);
+
+		let actual = format!(
+			"{}",
+			Ipv6Error::ZoneIdNotSupported("fe80::1%eth0".to_string())
+		);
+		assert_eq!(
+			actual,
+			"\"fe80::1%eth0\" has a zone ID, which is not supported"
+		);
+	}
+
+	#[test]
+	fn error_test() {
+		let fixture: Box<dyn std::error::Error> = Box::new(Ipv6Error::IndexOutOfRange(8));
+		assert!(fixture.source().is_none());
+	}
+}
@@ -0,0 +1,191 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::ipv6::IPv6;
+use crate::ipv6_mask_error::IPv6MaskError;
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub struct IPv6Mask(IPv6, u8);
+
+impl IPv6Mask {
+	pub fn new(cidr: u8) -> Result<Self, IPv6MaskError> {
+		match cidr {
+			0 => Ok(IPv6Mask(IPv6::from(0u128), 0)),
+			1..=128 => Ok(IPv6Mask(IPv6::from(u128::MAX << (128 - cidr)), cidr)),
+			_ => Err(IPv6MaskError::CidrOutOfRange(cidr as u32)),
+		}
+	}
+
+	pub fn cidr(&self) -> u8 {
+		self.1
+	}
+
+	pub fn mask_address(&self) -> &IPv6 {
+		&self.0
+	}
+
+	pub fn network_address(&self, ip: &IPv6) -> IPv6 {
+		IPv6::from(self.0.to_u128() & ip.to_u128())
+	}
+
+	/// IPv6 has no broadcast address; this is simply the highest address
+	/// sharing the prefix.
+	pub fn last_address(&self, ip: &IPv6) -> IPv6 {
+		IPv6::from(ip.to_u128() | !self.0.to_u128())
+	}
+
+	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "/{}", self.1)
+	}
+}
+
+impl TryFrom<&IPv6> for IPv6Mask {
+	type Error = IPv6MaskError;
+
+	fn try_from(value: &IPv6) -> Result<Self, Self::Error> {
+		let mask = value.to_u128();
+		let cidr = mask.leading_ones();
+
+		if mask.count_ones() == cidr {
+			Ok(IPv6Mask::new(cidr as u8).unwrap())
+		} else {
+			Err(IPv6MaskError::InvalidSubnetMask(*value))
+		}
+	}
+}
+
+impl TryFrom<&str> for IPv6Mask {
+	type Error = IPv6MaskError;
+
+	/// Parses a prefix length (`64` or `/64`) or a mask written as an
+	/// address (`ffff:ffff:ffff:ffff::`).
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		let format_error = || IPv6MaskError::FormatError(value.to_string());
+		let value = value.strip_prefix('/').unwrap_or(value);
+
+		if value.contains(':') {
+			let mask = IPv6::try_from(value).map_err(|_| format_error())?;
+			IPv6Mask::try_from(&mask)
+		} else {
+			if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+				return Err(format_error());
+			}
+			let cidr = value.parse::<u32>().map_err(|_| format_error())?;
+			let cidr = u8::try_from(cidr).map_err(|_| IPv6MaskError::CidrOutOfRange(cidr))?;
+			IPv6Mask::new(cidr)
+		}
+	}
+}
+
+impl FromStr for IPv6Mask {
+	type Err = IPv6MaskError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		IPv6Mask::try_from(s)
+	}
+}
+
+impl Debug for IPv6Mask {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+impl Display for IPv6Mask {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::ipv6::IPv6;
+	use crate::ipv6_mask::IPv6Mask;
+	use crate::ipv6_mask_error::IPv6MaskError;
+
+	fn assert_error<T>(actual: Result<T, IPv6MaskError>, expected: IPv6MaskError) {
+		match actual {
+			Ok(_) => unreachable!(),
+			Err(err) => assert_eq!(err, expected),
+		}
+	}
+
+	fn format_error(input: &str) -> IPv6MaskError {
+		IPv6MaskError::FormatError(input.to_string())
+	}
+
+	#[test]
+	fn new_test() {
+		for cidr in 0u8..=128 {
+			let actual = IPv6Mask::new(cidr).unwrap();
+			assert_eq!(actual.cidr(), cidr);
+			assert_eq!(actual.mask_address().to_u128().count_ones(), cidr as u32);
+			assert_eq!(actual.mask_address().to_u128().leading_ones(), cidr as u32);
+		}
+
+		assert_error(IPv6Mask::new(129), IPv6MaskError::CidrOutOfRange(129));
+	}
+
+	#[test]
+	fn network_address_test() {
+		let mask = IPv6Mask::new(32).unwrap();
+		let ip = IPv6::try_from("2001:db8:1234::1").unwrap();
+
+		assert_eq!(
+			mask.network_address(&ip),
+			IPv6::try_from("2001:db8::").unwrap()
+		);
+		assert_eq!(
+			mask.last_address(&ip),
+			IPv6::try_from("2001:db8:ffff:ffff:ffff:ffff:ffff:ffff").unwrap()
+		);
+	}
+
+	#[test]
+	fn try_from_ipv6_test() {
+		let actual = IPv6Mask::try_from(&IPv6::try_from("ffff:ffff:ffff:ff00::").unwrap());
+		assert_eq!(actual.unwrap().cidr(), 56);
+
+		let actual = IPv6Mask::try_from(&IPv6::try_from("::").unwrap());
+		assert_eq!(actual.unwrap().cidr(), 0);
+
+		let actual = IPv6Mask::try_from(&IPv6::try_from("ffff::1").unwrap());
+		assert_error(
+			actual,
+			IPv6MaskError::InvalidSubnetMask(IPv6::try_from("ffff::1").unwrap()),
+		);
+	}
+
+	#[test]
+	fn try_from_str_test() {
+		assert_eq!(IPv6Mask::try_from("64").unwrap().cidr(), 64);
+		assert_eq!(IPv6Mask::try_from("/128").unwrap().cidr(), 128);
+		assert_eq!(IPv6Mask::try_from("ffff:ffff::").unwrap().cidr(), 32);
+		assert_eq!("/0".parse::<IPv6Mask>().unwrap().cidr(), 0);
+
+		assert_error(
+			IPv6Mask::try_from("129"),
+			IPv6MaskError::CidrOutOfRange(129),
+		);
+		assert_error(
+			IPv6Mask::try_from("1000"),
+			IPv6MaskError::CidrOutOfRange(1000),
+		);
+		assert_error(IPv6Mask::try_from(""), format_error(""));
+		assert_error(IPv6Mask::try_from("-1"), format_error("-1"));
+		assert_error(IPv6Mask::try_from("+64"), format_error("+64"));
+		assert_error(IPv6Mask::try_from("/+64"), format_error("/+64"));
+		assert_error(IPv6Mask::try_from("ffff:::"), format_error("ffff:::"));
+		assert_error(
+			IPv6Mask::try_from("ffff:0:ffff::"),
+			IPv6MaskError::InvalidSubnetMask(IPv6::try_from("ffff:0:ffff::").unwrap()),
+		);
+	}
+
+	#[test]
+	fn display_test() {
+		let fixture = IPv6Mask::new(64).unwrap();
+		assert_eq!(format!("{}", fixture), "/64");
+		assert_eq!(format!("{:?}", fixture), "/64");
+	}
+}
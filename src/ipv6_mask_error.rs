@@ -0,0 +1,62 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::ipv6::IPv6;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IPv6MaskError {
+	CidrOutOfRange(u32),
+	FormatError(String),
+	InvalidSubnetMask(IPv6),
+}
+
+impl Display for IPv6MaskError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			IPv6MaskError::CidrOutOfRange(cidr) => {
+				write!(
+					f,
+					"prefix length {} is out of range (expected 0 to 128)",
+					cidr
+				)
+			}
+			IPv6MaskError::FormatError(input) => {
+				write!(f, "\"{}\" is not a prefix length or IPv6 mask", input)
+			}
+			IPv6MaskError::InvalidSubnetMask(mask) => write!(
+				f,
+				"{} is not a subnet mask: its one bits must be contiguous",
+				mask
+			),
+		}
+	}
+}
+
+impl Error for IPv6MaskError {}
+
+#[cfg(test)]
+mod tests {
+	use crate::ipv6::IPv6;
+	use crate::ipv6_mask_error::IPv6MaskError;
+
+	#[test]
+	fn display_test() {
+		let fixture = IPv6MaskError::CidrOutOfRange(129);
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"prefix length 129 is out of range (expected 0 to 128)"
+		);
+
+		let fixture = IPv6MaskError::FormatError("x".to_string());
+		let actual = format!("{}", fixture);
+		assert_eq!(actual, "\"x\" is not a prefix length or IPv6 mask");
+
+		let fixture = IPv6MaskError::InvalidSubnetMask(IPv6::try_from("ffff::1").unwrap());
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"ffff::1 is not a subnet mask: its one bits must be contiguous"
+		);
+	}
+}
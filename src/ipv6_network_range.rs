@@ -0,0 +1,105 @@
+use crate::ipv6::IPv6;
+use crate::ipv6_network_range_error::IPv6NetworkRangeError;
+use std::fmt::{Debug, Display, Formatter};
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub struct IPv6NetworkRange {
+	begin: IPv6,
+	end: IPv6,
+}
+
+impl IPv6NetworkRange {
+	pub fn new(begin: IPv6, end: IPv6) -> Result<IPv6NetworkRange, IPv6NetworkRangeError> {
+		if begin > end {
+			Err(IPv6NetworkRangeError::BeginGreaterThanEnd { begin, end })
+		} else {
+			Ok(IPv6NetworkRange { begin, end })
+		}
+	}
+
+	pub fn contain(&self, address: &IPv6) -> bool {
+		&self.begin <= address && address <= &self.end
+	}
+
+	pub fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} - {}", self.begin, self.end)
+	}
+
+	pub fn begin(&self) -> &IPv6 {
+		&self.begin
+	}
+
+	pub fn end(&self) -> &IPv6 {
+		&self.end
+	}
+}
+
+impl Debug for IPv6NetworkRange {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+impl Display for IPv6NetworkRange {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::IPv6NetworkRange;
+	use crate::ipv6::IPv6;
+	use crate::ipv6_network_range_error::IPv6NetworkRangeError;
+	use once_cell::sync::Lazy;
+	static EXPECTED_BEGIN: Lazy<IPv6> = Lazy::new(|| IPv6::try_from("2001:db8::").unwrap());
+	static EXPECTED_END: Lazy<IPv6> =
+		Lazy::new(|| IPv6::try_from("2001:db8::ffff:ffff:ffff:ffff").unwrap());
+
+	fn fixture() -> IPv6NetworkRange {
+		IPv6NetworkRange::new(*EXPECTED_BEGIN, *EXPECTED_END).unwrap()
+	}
+
+	#[test]
+	fn new_test() {
+		let actual = fixture();
+		assert_eq!(actual.begin(), &*EXPECTED_BEGIN);
+		assert_eq!(actual.end(), &*EXPECTED_END);
+
+		let actual = IPv6NetworkRange::new(*EXPECTED_END, *EXPECTED_BEGIN);
+		assert_eq!(
+			actual.unwrap_err(),
+			IPv6NetworkRangeError::BeginGreaterThanEnd {
+				begin: *EXPECTED_END,
+				end: *EXPECTED_BEGIN,
+			}
+		);
+	}
+
+	#[test]
+	fn contain_test() {
+		let fixture = fixture();
+
+		assert!(fixture.contain(&EXPECTED_BEGIN));
+		assert!(fixture.contain(&EXPECTED_END));
+		assert!(fixture.contain(&IPv6::try_from("2001:db8::abcd").unwrap()));
+
+		assert!(
+			!fixture.contain(&IPv6::try_from("2001:db7:ffff:ffff:ffff:ffff:ffff:ffff").unwrap())
+		);
+		assert!(!fixture.contain(&IPv6::try_from("2001:db8:0:1::").unwrap()));
+	}
+
+	#[test]
+	fn display_test() {
+		let fixture = fixture();
+		assert_eq!(
+			format!("{}", fixture),
+			"2001:db8:: - 2001:db8::ffff:ffff:ffff:ffff"
+		);
+		assert_eq!(
+			format!("{:?}", fixture),
+			"2001:db8:: - 2001:db8::ffff:ffff:ffff:ffff"
+		);
+	}
+}
@@ -0,0 +1,40 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::ipv6::IPv6;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IPv6NetworkRangeError {
+	BeginGreaterThanEnd { begin: IPv6, end: IPv6 },
+}
+
+impl Display for IPv6NetworkRangeError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			IPv6NetworkRangeError::BeginGreaterThanEnd { begin, end } => {
+				write!(f, "range begin {} is greater than its end {}", begin, end)
+			}
+		}
+	}
+}
+
+impl Error for IPv6NetworkRangeError {}
+
+#[cfg(test)]
+mod tests {
+	use crate::ipv6::IPv6;
+	use crate::ipv6_network_range_error::IPv6NetworkRangeError;
+
+	#[test]
+	fn display_test() {
+		let fixture = IPv6NetworkRangeError::BeginGreaterThanEnd {
+			begin: IPv6::try_from("2001:db8::9").unwrap(),
+			end: IPv6::try_from("2001:db8::1").unwrap(),
+		};
+		let actual = format!("{}", fixture);
+		assert_eq!(
+			actual,
+			"range begin 2001:db8::9 is greater than its end 2001:db8::1"
+		);
+	}
+}
@@ -0,0 +1,83 @@
+//! IPv4 and IPv6 addresses, masks, CIDR blocks and ranges, with the set and
+//! trie types built on them. The `cidr_checker` binary is a thin CLI over
+//! this crate.
+//!
+//! ```
+//! use cidr_checker::{Cidr, IPv4};
+//!
+//! let cidr = Cidr::try_from("192.168.10.0/24")?;
+//! assert!(cidr.contains(&IPv4::try_from("192.168.10.15")?));
+//! assert!(!cidr.contains(&IPv4::try_from("192.168.19.15")?));
+//! # Ok::<(), cidr_checker::Error>(())
+//! ```
+//!
+//! Every error converts into [`Error`], so one `?` chain can mix addresses,
+//! masks and blocks of both families.
+
+// The `eq_test`/`ne_test` cases spell out `!(a == b)` on purpose to exercise
+// both operators, which clippy's `nonminimal_bool` would rewrite.
+#![cfg_attr(test, allow(clippy::nonminimal_bool))]
+
+mod addresses;
+mod cidr;
+mod cidr6;
+mod cidr6_error;
+mod cidr_error;
+pub mod cidr_list;
+mod cidr_map;
+mod cidr_set;
+mod error;
+mod ip_address;
+mod ip_address_error;
+mod ip_cidr;
+mod ip_cidr_error;
+mod ip_cidr_map;
+mod ip_cidr_set;
+mod ipv4;
+mod ipv4_error;
+mod ipv4_interface;
+mod ipv4_mask;
+mod ipv4_mask_error;
+mod ipv4_network_range;
+mod ipv4_network_range_error;
+mod ipv4_parse_mode;
+mod ipv6;
+mod ipv6_error;
+mod ipv6_mask;
+mod ipv6_mask_error;
+mod ipv6_network_range;
+mod ipv6_network_range_error;
+mod prefix;
+pub mod special_purpose;
+mod subnets;
+
+pub use addresses::Addresses;
+pub use cidr::Cidr;
+pub use cidr6::Cidr6;
+pub use cidr6_error::Cidr6Error;
+pub use cidr_error::CidrError;
+pub use cidr_map::{CidrMap, Iter as CidrMapIter};
+pub use cidr_set::CidrSet;
+pub use error::Error;
+pub use ip_address::IpAddress;
+pub use ip_address_error::IpAddressError;
+pub use ip_cidr::IpCidr;
+pub use ip_cidr_error::IpCidrError;
+pub use ip_cidr_map::IpCidrMap;
+pub use ip_cidr_set::IpCidrSet;
+pub use ipv4::IPv4;
+pub use ipv4_error::Ipv4Error;
+pub use ipv4_interface::IPv4Interface;
+pub use ipv4_mask::IPv4Mask;
+pub use ipv4_mask_error::IPv4MaskError;
+pub use ipv4_network_range::IPv4NetworkRange;
+pub use ipv4_network_range_error::IPv4NetworkRangeError;
+pub use ipv4_parse_mode::IPv4ParseMode;
+pub use ipv6::IPv6;
+pub use ipv6_error::Ipv6Error;
+pub use ipv6_mask::IPv6Mask;
+pub use ipv6_mask_error::IPv6MaskError;
+pub use ipv6_network_range::IPv6NetworkRange;
+pub use ipv6_network_range_error::IPv6NetworkRangeError;
+pub use prefix::{FamilyPrefix, Prefix};
+pub use subnets::Subnets;
@@ -1,33 +1,102 @@
 use std::env;
+use std::process;
 
-mod cidr;
-mod cidr_error;
+use cidr_checker::Prefix;
+
+use crate::command_data::CommandData;
+use crate::command_error::CommandError;
+
+mod aggregate_command;
+mod batch_command;
 mod command_data;
-mod ipv4;
-mod ipv4_error;
-mod ipv4_mask;
-mod ipv4_mask_error;
-mod ipv4_network_range;
+mod command_error;
+
+const EXIT_SUCCESS: i32 = 0;
+const EXIT_CONTAINED: i32 = 0;
+const EXIT_NOT_CONTAINED: i32 = 1;
+const EXIT_SELECTED: i32 = 0;
+const EXIT_NOT_SELECTED: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+
+const USAGE: &str = "\
+usage: cidr_checker [--strict] -c <network>/<prefix> -a <address>
+       cidr_checker [--strict] -n <network> -s <subnet mask> -a <address>
+       cidr_checker aggregate [FILE...]
+       cidr_checker batch -f RULES [--strict] [-v] [-c] [-o] [-k COLUMN] [FILE...]
+
+-c and -a accept IPv4 or IPv6; -n and -s describe an IPv4 network.
+IPv4 networks and rules never match IPv4-mapped addresses (::ffff:a.b.c.d).
+--strict rejects IPv4 octets and prefix lengths with leading zeros or
+signs, and hexadecimal masks, so every value has one spelling.
+aggregate reads one CIDR per line (stdin when no FILE or FILE is -) and
+prints the smallest equivalent list. IPv4 and IPv6 blocks may be mixed.
+batch prints the lines of each FILE (stdin when none) whose address is in
+a CIDR listed in RULES; -v prints the others instead, -c only counts them
+(per FILE, as FILE:COUNT when there are several) and -o prints just the
+address. -k N reads the address from the Nth comma-separated column.
+Invalid lines are reported and skipped.
+
+exit status: 0 if the address is contained (batch: if any line is
+selected), 1 if not, 2 on error";
 
 //-c 192.168.10.0/24
 //-s 255.255.255.0(with -n
 //-a 192.168.19.15
 //-n 192.168.10.0 (With -s
 fn main() {
-	let args: Vec<_> = env::args().collect();
-	let a: Vec<_> = env::args().collect();
+	let args: Vec<_> = env::args().skip(1).collect();
+	let mut scr = args.iter();
+
+	let result = match args.first().map(|s| s.as_str()) {
+		Some("aggregate") => {
+			scr.next();
+			aggregate(&mut scr)
+		}
+		Some("batch") => {
+			scr.next();
+			batch(&mut scr)
+		}
+		_ => check(&mut scr),
+	};
 
-	for elem in args {
-		println!("{}", elem)
+	match result {
+		Ok(code) => process::exit(code),
+		Err(e) => {
+			eprintln!("{}", e);
+			if !matches!(e, CommandError::InvalidLine(..) | CommandError::Io(..)) {
+				eprintln!("{}", USAGE);
+			}
+			process::exit(EXIT_USAGE)
+		}
+	}
+}
+
+fn aggregate<'a, T: Iterator<Item = &'a String>>(scr: &mut T) -> Result<i32, CommandError> {
+	for cidr in aggregate_command::run(scr)? {
+		println!("{}", cidr);
 	}
 
-	for i in 0..a.len() {
-		println!("{}:{}", i, a[i])
+	Ok(EXIT_SUCCESS)
+}
+
+fn batch<'a, T: Iterator<Item = &'a String>>(scr: &mut T) -> Result<i32, CommandError> {
+	if batch_command::run(scr)? {
+		Ok(EXIT_SELECTED)
+	} else {
+		Ok(EXIT_NOT_SELECTED)
 	}
 }
 
-fn hoge<'a, T: Iterator<Item = &'a String>>(scr: &mut T) {
-	for elem in scr {
-		println!("{}", elem)
+fn check<'a, T: Iterator<Item = &'a String>>(scr: &mut T) -> Result<i32, CommandError> {
+	let data = CommandData::parse(scr)?;
+	let cidr = data.target()?;
+	let address = data.address()?;
+
+	if cidr.contains(&address) {
+		println!("{} is contained in {}", address, cidr);
+		Ok(EXIT_CONTAINED)
+	} else {
+		println!("{} is not contained in {}", address, cidr);
+		Ok(EXIT_NOT_CONTAINED)
 	}
 }
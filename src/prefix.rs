@@ -0,0 +1,41 @@
+/// Operations shared by CIDR blocks of every address family, so that lists,
+/// tries and the CLI can be written once for `Cidr`, `Cidr6` and `IpCidr`.
+pub trait Prefix: Copy + Ord {
+	type Address: Copy + Ord;
+
+	fn contains(&self, address: &Self::Address) -> bool;
+
+	/// The first address of the block.
+	fn network(&self) -> Self::Address;
+
+	/// The last address of the block (the broadcast address for IPv4).
+	fn last(&self) -> Self::Address;
+
+	fn prefix_len(&self) -> u8;
+
+	fn overlaps(&self, other: &Self) -> bool;
+
+	/// The block one bit shorter, or `None` for `/0`.
+	fn supernet(&self) -> Option<Self>;
+
+	/// The two blocks one bit longer, lower half first, or `None` for a
+	/// single address.
+	fn halves(&self) -> Option<(Self, Self)>;
+
+	fn contains_prefix(&self, other: &Self) -> bool {
+		self.prefix_len() <= other.prefix_len() && self.contains(&other.network())
+	}
+}
+
+/// A `Prefix` of a single address family, viewed as a left-aligned bit string.
+/// This is what `CidrMap` walks bit by bit.
+pub trait FamilyPrefix: Prefix {
+	/// The address width, i.e. the longest valid prefix length.
+	const BITS: u8;
+
+	/// The address bits, left-aligned in a `u128`.
+	fn to_bits(address: &Self::Address) -> u128;
+
+	/// The block of length `prefix_len` containing the left-aligned `bits`.
+	fn from_bits(bits: u128, prefix_len: u8) -> Self;
+}
@@ -0,0 +1,169 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use once_cell::sync::Lazy;
+
+use crate::cidr::Cidr;
+
+/// An entry of the IANA IPv4 Special-Purpose Address Registry (RFC 6890).
+pub struct SpecialPurposeEntry {
+	cidr: Cidr,
+	name: &'static str,
+	rfc: &'static str,
+	source: bool,
+	destination: bool,
+	forwardable: bool,
+	global: bool,
+	reserved_by_protocol: bool,
+}
+
+impl SpecialPurposeEntry {
+	pub fn cidr(&self) -> &Cidr {
+		&self.cidr
+	}
+
+	pub fn name(&self) -> &'static str {
+		self.name
+	}
+
+	pub fn rfc(&self) -> &'static str {
+		self.rfc
+	}
+
+	pub fn source(&self) -> bool {
+		self.source
+	}
+
+	pub fn destination(&self) -> bool {
+		self.destination
+	}
+
+	pub fn forwardable(&self) -> bool {
+		self.forwardable
+	}
+
+	pub fn global(&self) -> bool {
+		self.global
+	}
+
+	pub fn reserved_by_protocol(&self) -> bool {
+		self.reserved_by_protocol
+	}
+
+	fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} {} ({})", self.cidr, self.name, self.rfc)
+	}
+}
+
+impl Debug for SpecialPurposeEntry {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+impl Display for SpecialPurposeEntry {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.format(f)
+	}
+}
+
+// cidr, name, rfc, source, destination, forwardable, global, reserved-by-protocol
+type Row = (
+	&'static str,
+	&'static str,
+	&'static str,
+	bool,
+	bool,
+	bool,
+	bool,
+	bool,
+);
+
+#[rustfmt::skip]
+const ROWS: [Row; 23] = [
+	("0.0.0.0/8", "\"This network\"", "RFC 791, Section 3.2", true, false, false, false, true),
+	("0.0.0.0/32", "\"This host on this network\"", "RFC 1122, Section 3.2.1.3", true, false, false, false, true),
+	("10.0.0.0/8", "Private-Use", "RFC 1918", true, true, true, false, false),
+	("100.64.0.0/10", "Shared Address Space", "RFC 6598", true, true, true, false, false),
+	("127.0.0.0/8", "Loopback", "RFC 1122, Section 3.2.1.3", false, false, false, false, true),
+	("169.254.0.0/16", "Link Local", "RFC 3927", true, true, false, false, true),
+	("172.16.0.0/12", "Private-Use", "RFC 1918", true, true, true, false, false),
+	("192.0.0.0/24", "IETF Protocol Assignments", "RFC 6890, Section 2.1", false, false, false, false, false),
+	("192.0.0.0/29", "IPv4 Service Continuity Prefix", "RFC 7335", true, true, true, false, false),
+	("192.0.0.8/32", "IPv4 dummy address", "RFC 7600", true, false, false, false, false),
+	("192.0.0.9/32", "Port Control Protocol Anycast", "RFC 7723", true, true, true, true, false),
+	("192.0.0.10/32", "Traversal Using Relays around NAT Anycast", "RFC 8155", true, true, true, true, false),
+	("192.0.0.170/32", "NAT64/DNS64 Discovery", "RFC 8880, RFC 7050, Section 2.2", false, false, false, false, true),
+	("192.0.0.171/32", "NAT64/DNS64 Discovery", "RFC 8880, RFC 7050, Section 2.2", false, false, false, false, true),
+	("192.0.2.0/24", "Documentation (TEST-NET-1)", "RFC 5737", false, false, false, false, false),
+	("192.31.196.0/24", "AS112-v4", "RFC 7535", true, true, true, true, false),
+	("192.52.193.0/24", "AMT", "RFC 7450", true, true, true, true, false),
+	("192.168.0.0/16", "Private-Use", "RFC 1918", true, true, true, false, false),
+	("198.18.0.0/15", "Benchmarking", "RFC 2544", true, true, true, false, false),
+	("198.51.100.0/24", "Documentation (TEST-NET-2)", "RFC 5737", false, false, false, false, false),
+	("203.0.113.0/24", "Documentation (TEST-NET-3)", "RFC 5737", false, false, false, false, false),
+	("240.0.0.0/4", "Reserved", "RFC 1112, Section 4", false, false, false, false, true),
+	("255.255.255.255/32", "Limited Broadcast", "RFC 8190, RFC 919, Section 7", false, true, false, false, true),
+];
+
+static REGISTRY: Lazy<Vec<SpecialPurposeEntry>> = Lazy::new(|| {
+	ROWS.iter()
+		.map(
+			|&(cidr, name, rfc, source, destination, forwardable, global, reserved_by_protocol)| {
+				SpecialPurposeEntry {
+					cidr: Cidr::try_from(cidr).unwrap(),
+					name,
+					rfc,
+					source,
+					destination,
+					forwardable,
+					global,
+					reserved_by_protocol,
+				}
+			},
+		)
+		.collect()
+});
+
+/// Every registry entry, sorted by block. The deprecated 6to4 relay anycast
+/// block (RFC 7526) is not included.
+pub fn registry() -> &'static [SpecialPurposeEntry] {
+	&REGISTRY
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::special_purpose::registry;
+
+	#[test]
+	fn registry_test() {
+		let actual = registry();
+		assert_eq!(actual.len(), 23);
+
+		let mut sorted: Vec<_> = actual.iter().map(|e| *e.cidr()).collect();
+		sorted.sort();
+		assert!(actual.iter().map(|e| *e.cidr()).eq(sorted));
+	}
+
+	#[test]
+	fn entry_test() {
+		let actual = registry()
+			.iter()
+			.find(|e| format!("{}", e.cidr()) == "100.64.0.0/10")
+			.unwrap();
+
+		assert_eq!(actual.name(), "Shared Address Space");
+		assert_eq!(actual.rfc(), "RFC 6598");
+		assert!(actual.source());
+		assert!(actual.destination());
+		assert!(actual.forwardable());
+		assert!(!actual.global());
+		assert!(!actual.reserved_by_protocol());
+	}
+
+	#[test]
+	fn display_test() {
+		let actual = format!("{}", registry()[2]);
+		assert_eq!(actual, "10.0.0.0/8 Private-Use (RFC 1918)");
+		assert_eq!(format!("{:?}", registry()[2]), actual);
+	}
+}
@@ -0,0 +1,110 @@
+use crate::cidr::Cidr;
+use crate::ipv4::IPv4;
+use crate::ipv4_mask::IPv4Mask;
+
+pub struct Subnets {
+	base: u64,
+	mask: IPv4Mask,
+	index: u64,
+	count: u64,
+}
+
+impl Subnets {
+	pub(crate) fn new(base: &IPv4, mask: IPv4Mask, count: u64) -> Subnets {
+		Subnets {
+			base: base.to_u32() as u64,
+			mask,
+			index: 0,
+			count,
+		}
+	}
+
+	fn subnet(&self, index: u64) -> Cidr {
+		let step = 1u64 << (32 - self.mask.cidr());
+		let address = IPv4::from((self.base + index * step) as u32);
+		Cidr::from_host(address, self.mask)
+	}
+
+	/// The `index`th subnet counted from the first, regardless of how far
+	/// the iterator has advanced. Unlike `nth`, `index` is never narrowed to
+	/// `usize`, so every subnet of a /0 is reachable on 32-bit targets.
+	pub(crate) fn get(&self, index: u64) -> Option<Cidr> {
+		if index < self.count {
+			Some(self.subnet(index))
+		} else {
+			None
+		}
+	}
+}
+
+impl Iterator for Subnets {
+	type Item = Cidr;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.index < self.count {
+			let subnet = self.subnet(self.index);
+			self.index += 1;
+			Some(subnet)
+		} else {
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match usize::try_from(self.count - self.index) {
+			Ok(remaining) => (remaining, Some(remaining)),
+			Err(_) => (usize::MAX, None),
+		}
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.index = self.index.saturating_add(n as u64).min(self.count);
+		self.next()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::cidr::Cidr;
+
+	#[test]
+	fn iterate_test() {
+		let fixture = Cidr::try_from("192.168.10.0/24").unwrap();
+		let actual: Vec<_> = fixture
+			.subnets(26)
+			.unwrap()
+			.map(|c| format!("{}", c))
+			.collect();
+
+		assert_eq!(
+			actual,
+			[
+				"192.168.10.0/26",
+				"192.168.10.64/26",
+				"192.168.10.128/26",
+				"192.168.10.192/26"
+			]
+		);
+	}
+
+	#[test]
+	fn size_hint_test() {
+		let fixture = Cidr::try_from("10.0.0.0/8").unwrap();
+		let mut actual = fixture.subnets(24).unwrap();
+
+		assert_eq!(actual.size_hint(), (65536, Some(65536)));
+		actual.next();
+		assert_eq!(actual.size_hint(), (65535, Some(65535)));
+	}
+
+	#[test]
+	fn nth_test() {
+		let fixture = Cidr::try_from("10.0.0.0/8").unwrap();
+		let mut actual = fixture.subnets(16).unwrap();
+
+		assert_eq!(format!("{}", actual.nth(200).unwrap()), "10.200.0.0/16");
+		assert_eq!(format!("{}", actual.next().unwrap()), "10.201.0.0/16");
+		assert!(actual.nth(54).is_none());
+		assert!(actual.next().is_none());
+	}
+}